@@ -0,0 +1,146 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+use crate::solution::{Answer, Part};
+
+/// File the known answers are read from when nothing else is configured.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers, keyed by year, day and part:
+///
+/// ```toml
+/// [2024.6]
+/// part1 = 41
+/// part2 = 6
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: HashMap<(u16, u8, Part), Answer>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, source: io::Error },
+    Toml { path: PathBuf, source: toml::de::Error },
+    InvalidKey { path: PathBuf, key: String },
+    InvalidValue { path: PathBuf, key: String },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, source } => {
+                write!(f, "could not read answers from '{}': {}", path.display(), source)
+            }
+            AnswersError::Toml { path, source } => {
+                write!(f, "could not parse answers in '{}': {}", path.display(), source)
+            }
+            AnswersError::InvalidKey { path, key } => {
+                write!(f, "invalid key '{}' in '{}'", key, path.display())
+            }
+            AnswersError::InvalidValue { path, key } => write!(
+                f,
+                "answer '{}' in '{}' must be an integer or a string",
+                key,
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Io { source, .. } => Some(source),
+            AnswersError::Toml { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = fs::read_to_string(path).map_err(|source| AnswersError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::parse(&text, path)
+    }
+
+    fn parse(text: &str, path: &Path) -> Result<Self, AnswersError> {
+        let path = || path.to_path_buf();
+        let table: Table = text
+            .parse()
+            .map_err(|source| AnswersError::Toml { path: path(), source })?;
+        let mut answers = HashMap::new();
+
+        for (year_key, days) in &table {
+            let (Ok(year), Value::Table(days)) = (year_key.parse::<u16>(), days) else {
+                return Err(AnswersError::InvalidKey { path: path(), key: year_key.clone() });
+            };
+
+            for (day_key, parts) in days {
+                let key = format!("{}.{}", year_key, day_key);
+                let (Ok(day), Value::Table(parts)) = (day_key.parse::<u8>(), parts) else {
+                    return Err(AnswersError::InvalidKey { path: path(), key });
+                };
+
+                for (part_key, value) in parts {
+                    let key = format!("{}.{}", key, part_key);
+                    let part = match part_key.as_str() {
+                        "part1" => Part::One,
+                        "part2" => Part::Two,
+                        _ => return Err(AnswersError::InvalidKey { path: path(), key }),
+                    };
+
+                    let answer = match value {
+                        Value::Integer(value) => Answer::Integer(*value),
+                        Value::String(value) => Answer::String(value.clone()),
+                        _ => return Err(AnswersError::InvalidValue { path: path(), key }),
+                    };
+
+                    answers.insert((year, day, part), answer);
+                }
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(year, day, part))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answers_by_year_day_and_part() {
+        let answers = Answers::parse(
+            "[2024.6]\npart1 = 41\npart2 = \"abc\"\n\n[2023.10]\npart1 = 8\n",
+            Path::new("answers.toml"),
+        ).unwrap();
+
+        assert_eq!(answers.get(2024, 6, Part::One), Some(&Answer::Integer(41)));
+        assert_eq!(answers.get(2024, 6, Part::Two), Some(&Answer::String("abc".to_string())));
+        assert_eq!(answers.get(2023, 10, Part::One), Some(&Answer::Integer(8)));
+        assert_eq!(answers.get(2023, 10, Part::Two), None);
+    }
+
+    #[test]
+    fn rejects_unknown_parts() {
+        assert!(matches!(
+            Answers::parse("[2024.6]\npart3 = 1\n", Path::new("answers.toml")),
+            Err(AnswersError::InvalidKey { key, .. }) if key == "2024.6.part3"
+        ));
+    }
+}
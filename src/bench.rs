@@ -0,0 +1,135 @@
+use std::{fmt::Write, hint::black_box, time::Duration, time::Instant};
+
+use crate::{input::Input, parse::ParseError, solution::Solution};
+
+/// Minimum, median and maximum of a series of measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to compute stats from");
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings of parsing and both parts of one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timings {
+    pub fn steps(&self) -> [(&'static str, Stats); 3] {
+        [("parse", self.parse), ("part1", self.part1), ("part2", self.part2)]
+    }
+}
+
+/// Parses the input and solves both parts `iterations` times, timing every step on its own.
+pub(crate) fn run<S: Solution>(input: &Input, iterations: usize) -> Result<Timings, ParseError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        part2.push(start.elapsed());
+    }
+
+    Ok(Timings {
+        year: S::YEAR,
+        day: S::DAY,
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_samples(part1),
+        part2: Stats::from_samples(part2),
+    })
+}
+
+/// Renders the timings as JSON, with durations in nanoseconds.
+pub fn to_json(iterations: usize, timings: &[Timings]) -> String {
+    let mut json = format!("{{\n  \"iterations\": {},\n  \"results\": [", iterations);
+
+    let mut separator = "";
+    for timing in timings {
+        for (step, stats) in timing.steps() {
+            write!(
+                json,
+                "{}\n    {{\"year\": {}, \"day\": {}, \"step\": \"{}\", \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                separator,
+                timing.year,
+                timing.day,
+                step,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            )
+            .unwrap();
+            separator = ",";
+        }
+    }
+
+    json.push_str("\n  ]\n}\n");
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&value| Duration::from_millis(value)).collect()
+    }
+
+    #[test]
+    fn stats_of_odd_number_of_samples() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(stats, Stats { min: Duration::from_millis(1), median: Duration::from_millis(3), max: Duration::from_millis(5) });
+    }
+
+    #[test]
+    fn stats_of_even_number_of_samples() {
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 8]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn json_lists_every_step() {
+        let stats = Stats::from_samples(millis(&[1]));
+        let timings = Timings { year: 2024, day: 6, parse: stats, part1: stats, part2: stats };
+
+        let json = to_json(1, &[timings]);
+        assert!(json.starts_with("{\n  \"iterations\": 1,"));
+        assert!(json.contains("{\"year\": 2024, \"day\": 6, \"step\": \"part2\", \"min_ns\": 1000000, \"median_ns\": 1000000, \"max_ns\": 1000000}"));
+        assert_eq!(json.matches("\"step\"").count(), 3);
+    }
+}
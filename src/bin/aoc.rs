@@ -0,0 +1,448 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use clap::{Args, Parser, Subcommand};
+
+use advent_of_code::{
+    answers::{self, Answers},
+    bench,
+    config::Config,
+    fetch::{self, Fetched},
+    input,
+    parse::ParseError,
+    registry::{self, Day},
+    scaffold,
+    solution::{Answer, Options, Part},
+    submit::{self, Bounds, History, Verdict},
+    verbosity::{self, Verbosity},
+};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Only print the answers
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    /// Print visualizations, repeat to trace every step
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution of a single day or of all days of a year
+    Run(RunArgs),
+    /// Checks the answers of all solutions against the recorded answers
+    Verify(VerifyArgs),
+    /// Times parsing and both parts of every solution
+    Bench(BenchArgs),
+    /// Downloads the puzzle input of a day into the input directory
+    Fetch(FetchArgs),
+    /// Runs one part of a day and submits its answer, unless the history rules it out
+    Submit(SubmitArgs),
+    /// Creates the module, fixture and tests of a new day and registers it
+    New(NewArgs),
+    /// Lists all available solutions
+    List,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    year: u16,
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+    /// Only run the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the puzzle input from this file instead of the input directory
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Run all days of the year
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// With -v, only show the antennas and antinodes of this frequency on the 2024 day 8 map
+    #[arg(long, value_name = "CHAR")]
+    frequency: Option<char>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify the solutions of this year
+    year: Option<u16>,
+    /// Only verify the solution of this day
+    #[arg(requires = "year")]
+    day: Option<u8>,
+    /// Read the recorded answers from this file
+    #[arg(long, default_value = answers::DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark the solutions of this year
+    year: Option<u16>,
+    /// Only benchmark the solution of this day
+    #[arg(requires = "year")]
+    day: Option<u8>,
+    /// Number of times every step is run
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+    /// Also write the timings as JSON to this file
+    #[arg(long)]
+    json: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    year: u16,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    year: u16,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Read the puzzle input from this file instead of the input directory
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Keep the submitted answers and responses in this file
+    #[arg(long, default_value = submit::DEFAULT_HISTORY_FILE)]
+    history: PathBuf,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    year: u16,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+fn select(year: Option<u16>, day: Option<u8>) -> Option<Vec<&'static Day>> {
+    let solutions: Vec<_> = match (year, day) {
+        (Some(year), Some(day)) => registry::find(year, day).into_iter().collect(),
+        (Some(year), None) => registry::for_year(year).collect(),
+        (None, _) => registry::DAYS.iter().collect(),
+    };
+
+    if solutions.is_empty() {
+        match (year, day) {
+            (Some(year), Some(day)) => eprintln!("no solution for {} day {}", year, day),
+            (Some(year), None) => eprintln!("no solutions for {}", year),
+            _ => eprintln!("no solutions"),
+        }
+        return None;
+    }
+
+    Some(solutions)
+}
+
+fn report_parse_error(solution: &Day, err: &ParseError) {
+    eprintln!("could not parse input for {} day {}: {}", solution.year, solution.day, err);
+    eprintln!("{}", err.snippet());
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let Some(solutions) = select(Some(args.year), args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let parts = match args.part {
+        Some(part) => vec![Part::try_from(part).expect("part is validated by clap")],
+        None => Part::ALL.to_vec(),
+    };
+
+    let options = Options { frequency: args.frequency };
+
+    let mut status = ExitCode::SUCCESS;
+    for solution in solutions {
+        if verbosity::enabled(Verbosity::Normal) {
+            println!("{} day {}", solution.year, solution.day);
+        }
+
+        let input = match input::read(solution.year, solution.day, args.input.clone()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{}", err);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let solved = solution.solve(&input, &parts, &options, &mut |part, answer| {
+            if verbosity::enabled(Verbosity::Normal) {
+                println!("part {}: {}", part, answer);
+            } else {
+                println!("{}", answer);
+            }
+        });
+
+        if let Err(err) = solved {
+            report_parse_error(solution, &err);
+            status = ExitCode::FAILURE;
+        }
+    }
+
+    status
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let answers = match Answers::load(&args.answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some(solutions) = select(args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for solution in solutions {
+        let input = match input::read(solution.year, solution.day, None) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{}", err);
+                failed += Part::ALL.len();
+                continue;
+            }
+        };
+
+        let solved = solution.solve(&input, &Part::ALL, &Options::default(), &mut |part, answer| {
+            let name = format!("{} day {} part {}", solution.year, solution.day, part);
+            match answers.get(solution.year, solution.day, part) {
+                Some(expected) if *expected == answer => {
+                    passed += 1;
+                    if verbosity::enabled(Verbosity::Normal) {
+                        println!("{}: pass", name);
+                    }
+                }
+                Some(expected) => {
+                    failed += 1;
+                    println!("{}: fail (expected {}, got {})", name, expected, answer);
+                }
+                None if answer == Answer::Unsolved => {}
+                None => {
+                    missing += 1;
+                    println!("{}: missing (got {})", name, answer);
+                }
+            }
+        });
+
+        if let Err(err) = solved {
+            report_parse_error(solution, &err);
+            failed += Part::ALL.len();
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let Some(solutions) = select(args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let iterations = args.iterations as usize;
+    let mut status = ExitCode::SUCCESS;
+    let mut timings = Vec::new();
+    for solution in solutions {
+        let input = match input::read(solution.year, solution.day, None) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{}", err);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        match solution.bench(&input, iterations) {
+            Ok(timing) => timings.push(timing),
+            Err(err) => {
+                report_parse_error(solution, &err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    println!("{:<12}{:<8}{:>12}{:>12}{:>12}", "day", "step", "min", "median", "max");
+    for timing in &timings {
+        let day = format!("{} {:02}", timing.year, timing.day);
+        for (step, stats) in timing.steps() {
+            println!(
+                "{:<12}{:<8}{:>12}{:>12}{:>12}",
+                day,
+                step,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max)
+            );
+        }
+    }
+
+    if let Some(path) = args.json {
+        if let Err(err) = fs::write(&path, bench::to_json(iterations, &timings)) {
+            eprintln!("could not write timings to '{}': {}", path.display(), err);
+            status = ExitCode::FAILURE;
+        }
+    }
+
+    status
+}
+
+fn fetch(args: FetchArgs) -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let path = input::resolve(args.year, args.day, None);
+    match fetch::fetch(&config, args.year, args.day, &path) {
+        Ok(Fetched::Cached(path)) => {
+            if verbosity::enabled(Verbosity::Normal) {
+                println!("already downloaded to {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            if verbosity::enabled(Verbosity::Normal) {
+                println!("downloaded to {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(args: SubmitArgs) -> ExitCode {
+    let Some(solution) = registry::find(args.year, args.day) else {
+        eprintln!("no solution for {} day {}", args.year, args.day);
+        return ExitCode::FAILURE;
+    };
+    let part = Part::try_from(args.part).expect("part is validated by clap");
+
+    let (config, mut history) = match (Config::load(), History::load(&args.history)) {
+        (Ok(config), Ok(history)) => (config, history),
+        (Err(err), _) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+        (_, Err(err)) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let input = match input::read(args.year, args.day, args.input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut answer = Answer::Unsolved;
+    if let Err(err) = solution.solve(&input, &[part], &Options::default(), &mut |_, solved| answer = solved) {
+        report_parse_error(solution, &err);
+        return ExitCode::FAILURE;
+    }
+    if answer == Answer::Unsolved {
+        eprintln!("{} day {} part {} is not solved yet", args.year, args.day, part);
+        return ExitCode::FAILURE;
+    }
+
+    let bounds = history.bounds(args.year, args.day, part);
+    if verbosity::enabled(Verbosity::Normal) {
+        if bounds == Bounds::default() {
+            println!("part {}: {}", part, answer);
+        } else {
+            println!("part {}: {} (the answer is {})", part, answer, bounds);
+        }
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    match submit::submit(&config, &mut history, (args.year, args.day, part), &answer.to_string(), now) {
+        Ok(submission) => {
+            match submission.wait {
+                Some(wait) => println!("{}, wait {}s before the next answer", submission.verdict, wait),
+                None => println!("{}", submission.verdict),
+            }
+            if submission.verdict == Verdict::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new(args: NewArgs) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    match scaffold::new_day(root, args.year, args.day) {
+        Ok(scaffold) => {
+            for path in &scaffold.created {
+                println!("created {}", path.strip_prefix(root).unwrap_or(path).display());
+            }
+            for path in &scaffold.updated {
+                println!("updated {}", path.strip_prefix(root).unwrap_or(path).display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list() -> ExitCode {
+    for solution in registry::DAYS {
+        println!("{} day {}", solution.year, solution.day);
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    verbosity::set(Verbosity::from_flags(cli.quiet, cli.verbose));
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args),
+        Command::List => list(),
+    }
+}
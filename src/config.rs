@@ -0,0 +1,141 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+/// Environment variable overriding the location of the config file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Config file read when nothing else is configured. It is fine if it does not exist.
+pub const DEFAULT_CONFIG_FILE: &str = "aoc.toml";
+
+/// Environment variable taking precedence over the `session` of the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable taking precedence over the `base_url` of the config file.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the Advent of Code website:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io { path: PathBuf, source: io::Error },
+    Toml { path: PathBuf, source: toml::de::Error },
+    InvalidValue { path: PathBuf, key: String },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => {
+                write!(f, "could not read config from '{}': {}", path.display(), source)
+            }
+            ConfigError::Toml { path, source } => {
+                write!(f, "could not parse config in '{}': {}", path.display(), source)
+            }
+            ConfigError::InvalidValue { path, key } => {
+                write!(f, "'{}' in '{}' must be a string", key, path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            ConfigError::Toml { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl Config {
+    /// Reads the config file from `AOC_CONFIG` or `aoc.toml` and applies the
+    /// overrides from the environment.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = env::var_os(CONFIG_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG_FILE));
+
+        let mut config = match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text, &path)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(source) => return Err(ConfigError::Io { path, source }),
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session.trim().to_string());
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    fn parse(text: &str, path: &Path) -> Result<Self, ConfigError> {
+        let table: Table = text.parse().map_err(|source| ConfigError::Toml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let string = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(Value::String(value)) => Ok(Some(value.trim().to_string())),
+            Some(_) => Err(ConfigError::InvalidValue {
+                path: path.to_path_buf(),
+                key: key.to_string(),
+            }),
+        };
+
+        Ok(Config {
+            session: string("session")?,
+            base_url: string("base_url")?.unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_session_and_base_url() {
+        let config = Config::parse("session = \"abc\\n\"\nbase_url = \"http://localhost:8080\"\n", Path::new("aoc.toml")).unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+    }
+
+    #[test]
+    fn defaults_to_the_website() {
+        let config = Config::parse("", Path::new("aoc.toml")).unwrap();
+        assert_eq!(config, Config::default());
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt::{self, Display};
+
+use crate::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four directions of the von Neumann neighbourhood, clockwise starting north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The four diagonal directions, clockwise starting north east.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+
+    /// All eight directions of the Moore neighbourhood, clockwise starting north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    fn from_index(index: usize) -> Self {
+        Self::ALL[index % Self::ALL.len()]
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn is_cardinal(&self) -> bool {
+        self.index().is_multiple_of(2)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !self.is_cardinal()
+    }
+
+    /// Offset of a single step, with y growing towards the south.
+    pub fn get_offset(&self) -> Point {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        };
+        Point::new(x, y)
+    }
+
+    /// Turns 90° clockwise.
+    pub fn rotate_right(&self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+
+    /// Turns 90° counter-clockwise.
+    pub fn rotate_left(&self) -> Self {
+        Self::from_index(self.index() + 6)
+    }
+
+    /// Turns 45° clockwise.
+    pub fn rotate_right_45(&self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Turns 45° counter-clockwise.
+    pub fn rotate_left_45(&self) -> Self {
+        Self::from_index(self.index() + 7)
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::from_index(self.index() + 4)
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::North => write!(f, "^"),
+            Direction::NorthEast => write!(f, "↗"),
+            Direction::East => write!(f, ">"),
+            Direction::SouthEast => write!(f, "↘"),
+            Direction::South => write!(f, "v"),
+            Direction::SouthWest => write!(f, "↙"),
+            Direction::West => write!(f, "<"),
+            Direction::NorthWest => write!(f, "↖"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDirection(pub char);
+
+impl Display for InvalidDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid direction '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDirection {}
+
+impl TryFrom<char> for Direction {
+    type Error = InvalidDirection;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' => Ok(Direction::North),
+            '↗' => Ok(Direction::NorthEast),
+            '>' => Ok(Direction::East),
+            '↘' => Ok(Direction::SouthEast),
+            'v' => Ok(Direction::South),
+            '↙' => Ok(Direction::SouthWest),
+            '<' => Ok(Direction::West),
+            '↖' => Ok(Direction::NorthWest),
+            _ => Err(InvalidDirection(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_and_reverses() {
+        assert_eq!(Direction::North.rotate_right(), Direction::East);
+        assert_eq!(Direction::North.rotate_left(), Direction::West);
+        assert_eq!(Direction::NorthWest.rotate_right_45(), Direction::North);
+        assert_eq!(Direction::North.rotate_left_45(), Direction::NorthWest);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.rotate_right().rotate_left(), direction);
+            assert_eq!(direction.rotate_right().rotate_right(), direction.opposite());
+            assert_eq!(direction.opposite().get_offset(), -direction.get_offset());
+        }
+    }
+
+    #[test]
+    fn offsets_grow_south_and_east() {
+        assert_eq!(Direction::North.get_offset(), Point::new(0, -1));
+        assert_eq!(Direction::East.get_offset(), Point::new(1, 0));
+        assert_eq!(Direction::SouthWest.get_offset(), Point::new(-1, 1));
+        assert!(Direction::CARDINAL.iter().all(|d| d.is_cardinal() && d.get_offset().manhattan(Point::ORIGIN) == 1));
+        assert!(Direction::DIAGONAL.iter().all(|d| d.is_diagonal() && d.get_offset().manhattan(Point::ORIGIN) == 2));
+    }
+
+    #[test]
+    fn converts_from_and_to_chars() {
+        for direction in Direction::ALL {
+            let c = direction.to_string().chars().next().unwrap();
+            assert_eq!(Direction::try_from(c), Ok(direction));
+        }
+        assert_eq!(Direction::try_from('x'), Err(InvalidDirection('x')));
+        assert_eq!(Direction::try_from('V'), Err(InvalidDirection('V')));
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    config::Config,
+    http::{self, HttpError},
+};
+
+const USER_AGENT: &str = "advent-of-code runner (aoc fetch)";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk, so nothing was requested.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Http(HttpError),
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Http(err) => write!(f, "{}", err),
+            FetchError::Io { path, source } => {
+                write!(f, "could not write input to '{}': {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Http(err) => Some(err),
+            FetchError::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+impl From<HttpError> for FetchError {
+    fn from(err: HttpError) -> Self {
+        FetchError::Http(err)
+    }
+}
+
+/// Downloads the input of a day to `path`, unless it has been downloaded before.
+pub fn fetch(config: &Config, year: u16, day: u8, path: &Path) -> Result<Fetched, FetchError> {
+    if path.exists() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    let input = http::get(config, &format!("/{}/day/{}/input", year, day), USER_AGENT)?;
+
+    // write to a temporary file first, so an interrupted download is never mistaken for a cached input
+    let io_error = |source| FetchError::Io { path: path.to_path_buf(), source };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let partial = path.with_extension("part");
+    fs::write(&partial, input).map_err(io_error)?;
+    fs::rename(&partial, path).map_err(io_error)?;
+
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+    use crate::http::stub;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("2024_16.txt")
+    }
+
+    #[test]
+    fn downloads_once_and_then_uses_the_cache() {
+        let (url, server) = stub::server(vec![(200, "###\n#.#\n")]);
+        let path = temp_path("cache");
+
+        assert_eq!(fetch(&stub::config(url.clone()), 2024, 16, &path).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "###\n#.#\n");
+
+        // the stub only answers once, so a second request would fail
+        assert_eq!(fetch(&stub::config(url), 2024, 16, &path).unwrap(), Fetched::Cached(path.clone()));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].head.starts_with("GET /2024/day/16/input HTTP/1.1\r\n"));
+        assert!(requests[0].head.to_lowercase().contains("cookie: session=secret\r\n"));
+    }
+
+    #[test]
+    fn reports_failed_requests_without_caching() {
+        let (url, server) = stub::server(vec![(404, "Please don't repeatedly request this endpoint before it unlocks!")]);
+        let path = temp_path("missing");
+
+        let err = fetch(&stub::config(url), 2024, 16, &path).unwrap_err();
+        assert!(matches!(err, FetchError::Http(HttpError::Status { status: 404, .. })));
+        assert!(!path.exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn needs_a_session() {
+        let path = temp_path("session");
+        let config = Config { session: None, ..Config::default() };
+        assert!(matches!(fetch(&config, 2024, 16, &path), Err(FetchError::Http(HttpError::NoSession))));
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{parse::ParseError, Direction, Point};
+
+/// Rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    columns: usize,
+    rows: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(columns: usize, rows: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            columns,
+            rows,
+            cells: vec![value; columns * rows],
+        }
+    }
+
+    /// Parses one line per row, converting every char with `f`.
+    pub fn parse_with<F>(text: &str, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut columns = None;
+        let mut rows = 0;
+        let mut cells = Vec::new();
+
+        for line in text.lines() {
+            let mut found = 0;
+            for (i, c) in line.char_indices() {
+                let Some(cell) = f(c) else {
+                    let offending = &line[i..i + c.len_utf8()];
+                    return Err(ParseError::at(text, offending, "invalid character"));
+                };
+                cells.push(cell);
+                found += 1;
+            }
+
+            let expected = *columns.get_or_insert(found);
+            if expected != found {
+                let message = format!("expected {} columns like the first line", expected);
+                return Err(ParseError::at(text, line, message));
+            }
+            rows += 1;
+        }
+
+        match columns {
+            Some(columns) if columns > 0 => Ok(Grid { columns, rows, cells }),
+            _ => Err(ParseError::missing(text, "grid is empty")),
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn is_in_bounds(&self, position: Point) -> bool {
+        self.index_of(position).is_some()
+    }
+
+    fn index_of(&self, position: Point) -> Option<usize> {
+        position.to_index(self.columns, self.rows)
+    }
+
+    fn position_of(&self, index: usize) -> Point {
+        Point::from_index(index, self.columns)
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.position_of(i))
+    }
+
+    /// All cells together with their position in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.position_of(i), cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The in-bounds neighbours of `position` in the given directions.
+    pub fn neighbours_in<'a>(
+        &'a self,
+        position: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Direction, Point, &'a T)> + 'a {
+        directions.iter().filter_map(move |&direction| {
+            let next = position + direction.get_offset();
+            self.get(next).map(|cell| (direction, next, cell))
+        })
+    }
+
+    /// The up to four orthogonally adjacent cells.
+    pub fn neighbours(&self, position: Point) -> impl Iterator<Item = (Direction, Point, &T)> {
+        self.neighbours_in(position, &Direction::CARDINAL)
+    }
+
+    /// The up to eight orthogonally and diagonally adjacent cells.
+    pub fn all_neighbours(
+        &self,
+        position: Point,
+    ) -> impl Iterator<Item = (Direction, Point, &T)> {
+        self.neighbours_in(position, &Direction::ALL)
+    }
+
+    /// Walks from `start` (inclusive) in `direction` until leaving the grid.
+    pub fn ray(
+        &self,
+        start: Point,
+        direction: Direction,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        let step = direction.get_offset();
+        let mut position = start;
+        std::iter::from_fn(move || {
+            let cell = self.get(position)?;
+            let current = position;
+            position += step;
+            Some((current, cell))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.ray(Point::new(0, y as i64), Direction::East).map(|(_, cell)| cell)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.ray(Point::new(x as i64, 0), Direction::South).map(|(_, cell)| cell)
+    }
+
+    /// Every maximal straight line through the grid running in `direction`,
+    /// e.g. all rows for `East` or all diagonals for `SouthEast`.
+    pub fn lines(
+        &self,
+        direction: Direction,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        let step = direction.get_offset();
+        self.positions()
+            .filter(move |&position| !self.is_in_bounds(position - step))
+            .map(move |start| self.ray(start, direction))
+    }
+
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        self.lines(Direction::SouthEast)
+    }
+
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        self.lines(Direction::SouthWest)
+    }
+
+    /// Positions of all cells equal to `value` in reading order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            columns: self.columns,
+            rows: self.rows,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {:?} is out of bounds", position))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {:?} is out of bounds", position))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Some)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.rows {
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "\
+abc
+def
+";
+
+    fn grid() -> Grid<char> {
+        TEXT.parse().unwrap()
+    }
+
+    fn chars<'a>(line: impl Iterator<Item = (Point, &'a char)>) -> String {
+        line.map(|(_, &c)| c).collect()
+    }
+
+    #[test]
+    fn parses_rows_of_equal_length() {
+        let grid = grid();
+        assert_eq!((grid.columns(), grid.rows()), (3, 2));
+
+        let ragged = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((ragged.line, ragged.column, ragged.text.as_str()), (1, 0, "de"));
+
+        let invalid = Grid::parse_with("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((invalid.line, invalid.column, invalid.text.as_str()), (1, 1, "x"));
+
+        assert_eq!("".parse::<Grid<char>>().unwrap_err().message, "grid is empty");
+        assert_eq!("\n\n".parse::<Grid<char>>().unwrap_err().message, "grid is empty");
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let mut grid = grid();
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert!(!grid.is_in_bounds(Point::new(0, -1)));
+
+        grid[Point::new(0, 1)] = 'x';
+        assert_eq!(grid.get_mut(Point::new(0, 1)), Some(&mut 'x'));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn panics_when_indexing_out_of_bounds() {
+        let _ = grid()[Point::new(3, 0)];
+    }
+
+    #[test]
+    fn finds_neighbours_within_the_grid() {
+        let grid = grid();
+        let neighbours = |position| grid.neighbours(position).map(|(_, _, &c)| c).collect::<String>();
+        let all_neighbours = |position| grid.all_neighbours(position).map(|(_, _, &c)| c).collect::<String>();
+
+        assert_eq!(neighbours(Point::new(0, 0)), "bd");
+        assert_eq!(neighbours(Point::new(1, 1)), "bfd");
+        assert_eq!(all_neighbours(Point::new(0, 0)), "bed");
+        assert_eq!(all_neighbours(Point::new(1, 0)), "cfeda");
+        assert_eq!(all_neighbours(Point::new(2, 1)), "ceb");
+        assert_eq!(
+            grid.neighbours(Point::new(2, 0)).map(|(direction, position, _)| (direction, position)).collect::<Vec<_>>(),
+            vec![(Direction::South, Point::new(2, 1)), (Direction::West, Point::new(1, 0))]
+        );
+    }
+
+    #[test]
+    fn walks_lines() {
+        let grid = grid();
+        assert_eq!(grid.lines(Direction::East).map(chars).collect::<Vec<_>>(), ["abc", "def"]);
+        assert_eq!(grid.lines(Direction::North).map(chars).collect::<Vec<_>>(), ["da", "eb", "fc"]);
+        assert_eq!(grid.diagonals().map(chars).collect::<Vec<_>>(), ["ae", "bf", "c", "d"]);
+        assert_eq!(grid.anti_diagonals().map(chars).collect::<Vec<_>>(), ["a", "bd", "ce", "f"]);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(chars(grid.ray(Point::new(1, 0), Direction::SouthEast)), "bf");
+    }
+
+    #[test]
+    fn finds_values() {
+        let grid: Grid<char> = "aba\nbab\n".parse().unwrap();
+        assert_eq!(grid.find(&'b'), Some(Point::new(1, 0)));
+        assert_eq!(grid.find_all(&'b').collect::<Vec<_>>(), [Point::new(1, 0), Point::new(0, 1), Point::new(2, 1)]);
+        assert_eq!(grid.find(&'c'), None);
+    }
+
+    #[test]
+    fn maps_and_prints() {
+        let grid = grid();
+        assert_eq!(grid.to_string(), TEXT);
+        assert_eq!(grid.to_string().parse::<Grid<char>>().unwrap(), grid);
+
+        let upper = grid.map(|c| c.to_ascii_uppercase());
+        assert_eq!(upper.to_string(), "ABC\nDEF\n");
+        assert_eq!(Grid::new(2, 1, 0).to_string(), "00\n");
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::{self, Display};
+
+use crate::config::{Config, BASE_URL_VAR, DEFAULT_CONFIG_FILE, SESSION_VAR};
+
+/// Why a request to the website failed.
+#[derive(Debug)]
+pub enum HttpError {
+    NoSession,
+    Status { method: &'static str, url: String, status: u16, message: String },
+    Transport { message: String },
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::NoSession => write!(
+                f,
+                "no session token, set {} or `session` in {}",
+                SESSION_VAR, DEFAULT_CONFIG_FILE
+            ),
+            HttpError::Status { method, url, status, message } => {
+                write!(f, "{} {} failed with status {}: {}", method, url, status, message)
+            }
+            HttpError::Transport { message } => write!(
+                f,
+                "{}\ncheck the base url, it can be changed with {}",
+                message, BASE_URL_VAR
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+/// Requests `path` below the base url with the session cookie and returns the body.
+pub fn get(config: &Config, path: &str, user_agent: &str) -> Result<String, HttpError> {
+    let (request, url) = request(config, "GET", path)?;
+    read("GET", url, request.set("User-Agent", user_agent).call())
+}
+
+/// Posts `form` to `path` below the base url with the session cookie and returns the body.
+pub fn post_form(config: &Config, path: &str, user_agent: &str, form: &[(&str, &str)]) -> Result<String, HttpError> {
+    let (request, url) = request(config, "POST", path)?;
+    read("POST", url, request.set("User-Agent", user_agent).send_form(form))
+}
+
+fn request(config: &Config, method: &str, path: &str) -> Result<(ureq::Request, String), HttpError> {
+    let session = config.session.as_deref().ok_or(HttpError::NoSession)?;
+    let url = format!("{}{}", config.base_url.trim_end_matches('/'), path);
+
+    let request = ureq::request(method, &url).set("Cookie", &format!("session={}", session));
+    Ok((request, url))
+}
+
+fn read(method: &'static str, url: String, response: Result<ureq::Response, ureq::Error>) -> Result<String, HttpError> {
+    let response = response.map_err(|err| match err {
+        ureq::Error::Status(status, response) => HttpError::Status {
+            method,
+            url,
+            status,
+            message: first_line(&response.into_string().unwrap_or_default()),
+        },
+        ureq::Error::Transport(transport) => HttpError::Transport {
+            message: transport.to_string(),
+        },
+    })?;
+
+    response.into_string().map_err(|err| HttpError::Transport {
+        message: err.to_string(),
+    })
+}
+
+fn first_line(body: &str) -> String {
+    body.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default().to_string()
+}
+
+/// A local server standing in for the website in tests.
+#[cfg(test)]
+pub mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use crate::config::Config;
+
+    /// A request as the stub received it.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        /// The request line and the headers, each ending in `\r\n`.
+        pub head: String,
+        pub body: String,
+    }
+
+    /// Serves `responses` one connection at a time and hands back the received requests.
+    pub fn server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut head = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    head.push_str(&line);
+                }
+                let mut received = vec![0; length];
+                reader.read_exact(&mut received).unwrap();
+                requests.push(Request { head, body: String::from_utf8(received).unwrap() });
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (url, handle)
+    }
+
+    /// A config with a session pointing at `base_url`.
+    pub fn config(base_url: String) -> Config {
+        Config {
+            session: Some("secret".to_string()),
+            base_url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_first_line_of_failed_requests() {
+        let (url, server) = stub::server(vec![(500, "\n  Internal error  \nmore details\n")]);
+
+        let err = get(&stub::config(url.clone()), "/2024/day/1/input", "test").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("GET {}/2024/day/1/input failed with status 500: Internal error", url)
+        );
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,210 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::PathBuf,
+};
+
+use crate::{parse::ParseError, Grid};
+
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory puzzle inputs are read from when nothing else is configured.
+pub const DEFAULT_INPUT_DIR: &str = "resources";
+
+#[derive(Debug)]
+pub struct InputError {
+    pub year: u16,
+    pub day: u8,
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not read input for {} day {} from '{}': {}",
+            self.year,
+            self.day,
+            self.path.display(),
+            self.source
+        )?;
+
+        if self.source.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                "\nsave your puzzle input as '{}', point {} to the directory containing it or pass the file path with --input",
+                self.path.display(),
+                INPUT_DIR_VAR
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// File name of the input for a day, e.g. `2024_06.txt`.
+pub fn file_name(year: u16, day: u8) -> String {
+    format!("{}_{:02}.txt", year, day)
+}
+
+/// Resolves where the input of a day lives. An explicit path wins, then the
+/// directory in `AOC_INPUT_DIR` and finally the default `resources/` directory.
+pub fn resolve(year: u16, day: u8, path: Option<PathBuf>) -> PathBuf {
+    if let Some(path) = path {
+        return path;
+    }
+
+    let dir = env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+    dir.join(file_name(year, day))
+}
+
+pub fn read(year: u16, day: u8, path: Option<PathBuf>) -> Result<Input, InputError> {
+    let path = resolve(year, day, path);
+    fs::read_to_string(&path)
+        .map(|text| Input::new(&text))
+        .map_err(|source| InputError {
+            year,
+            day,
+            path,
+            source,
+        })
+}
+
+/// Puzzle input with a leading BOM, `\r\n` line endings and trailing blank
+/// lines removed, where lines of only whitespace count as blank. Spaces at the
+/// end of other lines are kept in the text, as they can be part of a grid.
+/// Non-empty input always ends with a single `\n`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    pub fn new(raw: &str) -> Self {
+        let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+
+        let mut text = String::with_capacity(raw.len());
+        let mut len = 0;
+        for line in raw.lines() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            text.push_str(line);
+            text.push('\n');
+            if !is_blank(line) {
+                len = text.len();
+            }
+        }
+        text.truncate(len);
+
+        Input { text }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The lines without whitespace at their end, for parsers reading line by line.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines().map(str::trim_end)
+    }
+
+    /// Sections separated by one or more blank lines, without their line breaks at either end.
+    pub fn blocks(&self) -> impl Iterator<Item = &str> {
+        let mut blocks = Vec::new();
+        let mut start = None;
+        let mut offset = 0;
+        for line in self.text.split_inclusive('\n') {
+            match (is_blank(line), start) {
+                (false, None) => start = Some(offset),
+                (true, Some(begin)) => {
+                    blocks.push(self.text[begin..offset].trim_end_matches('\n'));
+                    start = None;
+                }
+                _ => {}
+            }
+            offset += line.len();
+        }
+        if let Some(begin) = start {
+            blocks.push(self.text[begin..].trim_end_matches('\n'));
+        }
+        blocks.into_iter()
+    }
+
+    /// The whole input as a grid of chars.
+    pub fn grid(&self) -> Result<Grid<char>, ParseError> {
+        self.grid_with(&self.text, Some)
+    }
+
+    /// Parses `section`, a slice of the input like one of its blocks, as a grid
+    /// converting every char with `f`. Errors point into the whole input.
+    pub fn grid_with<T, F>(&self, section: &str, f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        Grid::parse_with(section, f).map_err(|err| err.within(&self.text, section))
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_line_endings_bom_and_trailing_whitespace() {
+        let input = Input::new("\u{feff}ab \r\ncd\r\n\r\n\n  \n");
+        assert_eq!(input.text(), "ab \ncd\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["ab", "cd"]);
+        assert_eq!(Input::new("ab\r").text(), "ab\n");
+        assert_eq!(Input::new(" \n\t\n").text(), "");
+    }
+
+    #[test]
+    fn keeps_trailing_spaces_within_lines() {
+        let input = Input::new("  # \r\n ##  \n");
+        assert_eq!(input.text(), "  # \n ##  \n");
+
+        let grid = Input::new("#  \n.. \n").grid().unwrap();
+        assert_eq!((grid.columns(), grid.rows()), (3, 2));
+    }
+
+    #[test]
+    fn splits_blocks_on_blank_lines() {
+        let input = Input::new("a\nb\r\n\r\nc\n\n\n\nd\n");
+        assert_eq!(input.blocks().collect::<Vec<_>>(), ["a\nb", "c", "d"]);
+    }
+
+    #[test]
+    fn splits_blocks_on_lines_of_whitespace() {
+        let input = Input::new("a\nb\n \nc\n\t \r\n\nd \n");
+        assert_eq!(input.blocks().collect::<Vec<_>>(), ["a\nb", "c", "d "]);
+    }
+
+    #[test]
+    fn grid_errors_point_into_the_whole_input() {
+        let input = Input::new("header\n\n..\n.x\n");
+        let grid = input.blocks().nth(1).unwrap();
+
+        let err = input.grid_with(grid, |c| (c == '.').then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "x"));
+    }
+
+    #[test]
+    fn crlf_grids_have_no_extra_column() {
+        let grid = Input::new("#.\r\n.#\r\n").grid().unwrap();
+        assert_eq!((grid.columns(), grid.rows()), (2, 2));
+    }
+}
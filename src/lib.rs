@@ -0,0 +1,25 @@
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod direction;
+pub mod fetch;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod math;
+pub mod matrix;
+pub mod parse;
+pub mod point;
+pub mod rational;
+pub mod registry;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod verbosity;
+pub mod year2023;
+pub mod year2024;
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use point::Point;
@@ -0,0 +1,179 @@
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// The integer types the number theory helpers work with.
+pub trait Integer:
+    Copy
+    + Ord
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+}
+
+impl<T> Integer for T where
+    T: Copy
+        + Ord
+        + From<u8>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+{
+}
+
+fn abs<T: Integer>(value: T) -> T {
+    let zero = T::from(0);
+    if value < zero {
+        zero - value
+    } else {
+        value
+    }
+}
+
+/// The greatest common divisor, which is never negative.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (abs(a), abs(b));
+    while b != T::from(0) {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, zero if either value is zero.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::from(0) || b == T::from(0) {
+        return T::from(0);
+    }
+    abs(a / gcd(a, b) * b)
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y == g`, where `g` is the gcd of `a` and `b`.
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> (T, T, T) {
+    let (zero, one) = (T::from(0), T::from(1));
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (one, zero);
+    let (mut old_y, mut y) = (zero, one);
+
+    while r != zero {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < zero {
+        (zero - old_r, zero - old_x, zero - old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as i64)
+}
+
+/// Solves `x ≡ residue (mod modulus)` for all pairs at once with the Chinese
+/// remainder theorem. Returns the smallest non-negative `x` together with the
+/// modulus all solutions repeat with, or `None` if the congruences contradict
+/// each other. The moduli do not need to be coprime.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut solution: (i128, i128) = (0, 1);
+
+    for &(residue, modulus) in congruences {
+        let (x, m) = solution;
+        let (residue, modulus) = ((residue as i128).rem_euclid(modulus as i128), modulus as i128);
+
+        // x + m * k ≡ residue (mod modulus)
+        let (g, inverse, _) = extended_gcd(m, modulus);
+        if (residue - x) % g != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let k = ((residue - x) / g * inverse).rem_euclid(step);
+        let combined = m * step;
+        solution = ((x + m * k).rem_euclid(combined), combined);
+    }
+
+    Some((solution.0.try_into().ok()?, solution.1.try_into().ok()?))
+}
+
+/// The number of decimal digits, where zero has one digit.
+pub fn count_digits(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// The decimal digits, most significant first.
+pub fn digits(n: u64) -> Vec<u8> {
+    let mut digits: Vec<u8> = std::iter::successors(Some(n), |&n| (n >= 10).then_some(n / 10))
+        .map(|n| (n % 10) as u8)
+        .collect();
+    digits.reverse();
+    digits
+}
+
+pub fn from_digits(digits: &[u8]) -> u64 {
+    digits.iter().fold(0, |n, &digit| n * 10 + digit as u64)
+}
+
+/// Writes the digits of `b` after the digits of `a`, e.g. 12 and 345 become 12345.
+pub fn concat(a: u64, b: u64) -> u64 {
+    a * 10u64.pow(count_digits(b)) + b
+}
+
+/// Splits off the last `count` digits, e.g. 12345 split at 2 becomes 123 and 45.
+pub fn split_digits(n: u64, count: u32) -> (u64, u64) {
+    let divisor = 10u64.pow(count);
+    (n / divisor, n % divisor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(0u64, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4i128, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+    }
+
+    #[test]
+    fn finds_bezout_coefficients_and_inverses() {
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!((g, 240 * x + 46 * y), (2, 2));
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn works_with_digits() {
+        assert_eq!(count_digits(0), 1);
+        assert_eq!(count_digits(1000), 4);
+        assert_eq!(digits(1024), vec![1, 0, 2, 4]);
+        assert_eq!(digits(0), vec![0]);
+        assert_eq!(from_digits(&[1, 0, 2, 4]), 1024);
+        assert_eq!(concat(12, 345), 12345);
+        assert_eq!(concat(12, 0), 120);
+        assert_eq!(split_digits(12345, 2), (123, 45));
+    }
+}
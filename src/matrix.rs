@@ -0,0 +1,243 @@
+use std::ops::{Index, IndexMut};
+
+use crate::rational::Rational;
+
+/// Matrix stored row by row, indexed by `(row, column)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    rows: usize,
+    columns: usize,
+    cells: Vec<T>,
+}
+
+/// The solutions of a system of linear equations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solutions {
+    None,
+    Unique(Vec<Rational>),
+    /// Every solution is `particular` with the `free` variables set to arbitrary values
+    /// and the others adjusted accordingly. `particular` has all free variables set to zero.
+    Infinite { particular: Vec<Rational>, free: Vec<usize> },
+}
+
+impl<T> Matrix<T> {
+    /// Panics if the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let columns = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == columns), "all rows of a matrix need the same length");
+
+        Matrix {
+            rows: rows.len(),
+            columns,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for column in 0..self.columns {
+            self.cells.swap(a * self.columns + column, b * self.columns + column);
+        }
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        assert!(column < self.columns, "column {} is out of bounds", column);
+        &self.cells[row * self.columns + column]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        assert!(column < self.columns, "column {} is out of bounds", column);
+        &mut self.cells[row * self.columns + column]
+    }
+}
+
+/// Result of bringing a matrix into reduced row echelon form.
+struct Reduction {
+    /// The column of the leading one of every non-zero row.
+    pivots: Vec<usize>,
+    /// The factor the determinant changed by, from row swaps and scaling.
+    determinant: Rational,
+}
+
+impl Matrix<Rational> {
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Matrix {
+            rows: size,
+            columns: size,
+            cells: vec![Rational::ZERO; size * size],
+        };
+        for i in 0..size {
+            matrix[(i, i)] = Rational::ONE;
+        }
+        matrix
+    }
+
+    /// Gauss-Jordan elimination in place.
+    fn reduce(&mut self) -> Reduction {
+        let mut pivots = Vec::new();
+        let mut determinant = Rational::ONE;
+
+        for column in 0..self.columns {
+            let row = pivots.len();
+            if row == self.rows {
+                break;
+            }
+
+            let Some(pivot_row) = (row..self.rows).find(|&r| !self[(r, column)].is_zero()) else {
+                continue;
+            };
+            if pivot_row != row {
+                self.swap_rows(pivot_row, row);
+                determinant = -determinant;
+            }
+
+            let pivot = self[(row, column)];
+            determinant = determinant * pivot;
+            for c in column..self.columns {
+                self[(row, c)] = self[(row, c)] / pivot;
+            }
+
+            for other in (0..self.rows).filter(|&other| other != row) {
+                let factor = self[(other, column)];
+                if factor.is_zero() {
+                    continue;
+                }
+                for c in column..self.columns {
+                    self[(other, c)] = self[(other, c)] - factor * self[(row, c)];
+                }
+            }
+
+            pivots.push(column);
+        }
+
+        Reduction { pivots, determinant }
+    }
+
+    /// The reduced row echelon form.
+    pub fn reduced(&self) -> Self {
+        let mut reduced = self.clone();
+        reduced.reduce();
+        reduced
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().reduce().pivots.len()
+    }
+
+    /// The determinant, if the matrix is square.
+    pub fn determinant(&self) -> Option<Rational> {
+        if self.rows != self.columns {
+            return None;
+        }
+
+        let reduction = self.clone().reduce();
+        Some(if reduction.pivots.len() == self.rows { reduction.determinant } else { Rational::ZERO })
+    }
+
+    /// Solves `self * x = rhs`.
+    ///
+    /// Panics if `rhs` does not have one value per row.
+    pub fn solve(&self, rhs: &[Rational]) -> Solutions {
+        assert_eq!(rhs.len(), self.rows, "the right hand side needs one value per row");
+
+        let mut augmented = Matrix::from_rows(
+            (0..self.rows)
+                .map(|row| self.row(row).iter().copied().chain([rhs[row]]).collect())
+                .collect(),
+        );
+        let pivots = augmented.reduce().pivots;
+
+        if pivots.last() == Some(&self.columns) {
+            // a row reads 0 = 1
+            return Solutions::None;
+        }
+
+        let mut particular = vec![Rational::ZERO; self.columns];
+        for (row, &column) in pivots.iter().enumerate() {
+            particular[column] = augmented[(row, self.columns)];
+        }
+
+        if pivots.len() == self.columns {
+            Solutions::Unique(particular)
+        } else {
+            let free = (0..self.columns).filter(|column| !pivots.contains(column)).collect();
+            Solutions::Infinite { particular, free }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: &[&[i64]]) -> Matrix<Rational> {
+        Matrix::from_rows(rows.iter().map(|row| row.iter().map(|&v| Rational::from(v)).collect()).collect())
+    }
+
+    fn values(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|&v| Rational::from(v)).collect()
+    }
+
+    #[test]
+    fn solves_unique_systems() {
+        // the first claw machine of 2024 day 13
+        let buttons = matrix(&[&[94, 22], &[34, 67]]);
+        assert_eq!(buttons.solve(&values(&[8400, 5400])), Solutions::Unique(values(&[80, 40])));
+
+        let system = matrix(&[&[2, 1, -1], &[-3, -1, 2], &[-2, 1, 2]]);
+        assert_eq!(system.solve(&values(&[8, -11, -3])), Solutions::Unique(values(&[2, 3, -1])));
+        assert_eq!(matrix(&[&[2, 0], &[0, 4]]).solve(&values(&[1, 1])), Solutions::Unique(vec![Rational::new(1, 2), Rational::new(1, 4)]));
+    }
+
+    #[test]
+    fn detects_singular_systems() {
+        let collinear = matrix(&[&[1, 2], &[2, 4]]);
+
+        assert_eq!(collinear.solve(&values(&[3, 7])), Solutions::None);
+        assert_eq!(
+            collinear.solve(&values(&[3, 6])),
+            Solutions::Infinite { particular: values(&[3, 0]), free: vec![1] }
+        );
+        assert_eq!(collinear.rank(), 1);
+        assert_eq!(collinear.determinant(), Some(Rational::ZERO));
+    }
+
+    #[test]
+    fn computes_rank_and_determinant() {
+        let m = matrix(&[&[0, 2, 1], &[1, 0, 3], &[4, 5, 6]]);
+        assert_eq!(m.determinant(), Some(Rational::from(17)));
+        assert_eq!(m.rank(), 3);
+        assert_eq!(m.reduced(), Matrix::identity(3));
+
+        assert_eq!(matrix(&[&[1, 2, 3], &[2, 4, 6]]).rank(), 1);
+        assert_eq!(matrix(&[&[1, 2, 3], &[2, 4, 6]]).determinant(), None);
+    }
+}
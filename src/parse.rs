@@ -0,0 +1,116 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Error pointing at the part of a puzzle input that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Zero based line of the offending text.
+    pub line: usize,
+    /// Zero based column of the offending text, counted in chars.
+    pub column: usize,
+    /// The offending text, empty if something is missing.
+    pub text: String,
+    /// The whole line containing the offending text.
+    pub line_text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for `offending`, which has to be a slice of `input`.
+    pub fn at(input: &str, offending: &str, message: impl Into<String>) -> Self {
+        let offset = (offending.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + offending.len() <= input.len())
+            .expect("offending text is not part of the input");
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+        ParseError {
+            line: input[..offset].matches('\n').count(),
+            column: input[line_start..offset].chars().count(),
+            text: offending.to_string(),
+            line_text: input[line_start..line_end].trim_end_matches('\r').to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for something missing from `input`, pointing at its start.
+    pub fn missing(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[..0], message)
+    }
+
+    /// Moves an error found while parsing `section` to its place in the surrounding `input`.
+    pub fn within(self, input: &str, section: &str) -> Self {
+        let outer = Self::at(input, section, "");
+        ParseError {
+            line: outer.line + self.line,
+            column: if self.line == 0 { outer.column + self.column } else { self.column },
+            line_text: if self.line == 0 { outer.line_text } else { self.line_text },
+            ..self
+        }
+    }
+
+    /// The offending line with a caret underneath the offending text.
+    pub fn snippet(&self) -> String {
+        let number = (self.line + 1).to_string();
+        let padding = " ".repeat(number.len());
+        let rest_of_line = self.line_text.chars().count().saturating_sub(self.column);
+        let carets = "^".repeat(self.text.chars().count().min(rest_of_line).max(1));
+
+        format!(
+            "{} |\n{} | {}\n{} | {}{}",
+            padding,
+            number,
+            self.line_text,
+            padding,
+            " ".repeat(self.column),
+            carets
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line + 1, self.column + 1, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ", found '{}'", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, "expected a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_offending_text() {
+        let input = "1 2\n3 x4 5\n";
+        let err = number::<i32>(input, &input[6..8]).unwrap_err();
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, "x4"));
+        assert_eq!(err.to_string(), "line 2, column 3: expected a number, found 'x4'");
+        assert_eq!(err.snippet(), "  |\n2 | 3 x4 5\n  |   ^^");
+    }
+
+    #[test]
+    fn moves_errors_into_the_surrounding_input() {
+        let input = "header\n\nab\ncd\n";
+        let section = &input[8..];
+        let err = ParseError::at(section, &section[4..5], "bad").within(input, section);
+
+        assert_eq!((err.line, err.column, err.line_text.as_str()), (3, 1, "cd"));
+    }
+}
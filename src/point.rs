@@ -0,0 +1,145 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Position or offset on a plane, where x grows towards the east and y towards the south.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// The point of the `index`th cell of a grid stored row by row.
+    pub fn from_index(index: usize, columns: usize) -> Self {
+        Point::new((index % columns) as i64, (index / columns) as i64)
+    }
+
+    /// The index of this point in a grid stored row by row, if it lies within the grid.
+    pub fn to_index(self, columns: usize, rows: usize) -> Option<usize> {
+        let (x, y) = (usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?);
+        (x < columns && y < rows).then_some(y * columns + x)
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Turns 90° clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Turns 90° counter-clockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+
+    /// Wraps both coordinates into `0..size.x` and `0..size.y`, like on a torus.
+    pub fn wrap(self, size: Point) -> Self {
+        Point::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_arithmetic() {
+        let (a, b) = (Point::new(3, -2), Point::new(-1, 5));
+
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+    }
+
+    #[test]
+    fn rotates_clockwise_with_y_pointing_south() {
+        let east = Point::new(1, 0);
+
+        assert_eq!(east.rotate_right(), Point::new(0, 1));
+        assert_eq!(east.rotate_left(), Point::new(0, -1));
+        assert_eq!(east.rotate_right().rotate_right().rotate_right().rotate_right(), east);
+    }
+
+    #[test]
+    fn wraps_and_converts_to_indices() {
+        assert_eq!(Point::new(-1, 9).wrap(Point::new(11, 7)), Point::new(10, 2));
+
+        assert_eq!(Point::from_index(7, 3), Point::new(1, 2));
+        assert_eq!(Point::new(1, 2).to_index(3, 3), Some(7));
+        assert_eq!(Point::new(3, 0).to_index(3, 3), None);
+        assert_eq!(Point::new(0, -1).to_index(3, 3), None);
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::math::gcd;
+
+/// Exact fraction, always stored in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numerator: 0, denominator: 1 };
+    pub const ONE: Rational = Rational { numerator: 1, denominator: 1 };
+
+    /// Panics if `denominator` is zero.
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "denominator of a rational must not be zero");
+
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Rational {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// Panics if the value is zero.
+    pub fn recip(self) -> Self {
+        Rational::new(self.denominator, self.numerator)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational { numerator: value.into(), denominator: 1 }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        Rational::new(
+            self.numerator * other.denominator + other.numerator * self.denominator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        Rational::new(self.numerator * other.numerator, self.denominator * other.denominator)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics if `other` is zero.
+    fn div(self, other: Rational) -> Rational {
+        Rational::new(self.numerator * other.denominator, self.denominator * other.numerator)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational { numerator: -self.numerator, ..self }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // denominators are positive, so cross multiplying keeps the order
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_lowest_terms() {
+        let half = Rational::new(2, 4);
+        assert_eq!((half.numerator(), half.denominator()), (1, 2));
+        assert_eq!(Rational::new(3, -6), Rational::new(-1, 2));
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+    }
+
+    #[test]
+    fn does_exact_arithmetic() {
+        let (third, half) = (Rational::new(1, 3), Rational::new(1, 2));
+
+        assert_eq!(third + half, Rational::new(5, 6));
+        assert_eq!(third - half, Rational::new(-1, 6));
+        assert_eq!(third * half, Rational::new(1, 6));
+        assert_eq!(third / half, Rational::new(2, 3));
+        assert!(third < half && -half < third);
+        assert_eq!((Rational::new(3, 4) * Rational::from(4)).to_integer(), Some(3));
+        assert_eq!(Rational::new(-7, 2).to_string(), "-7/2");
+    }
+}
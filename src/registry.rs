@@ -0,0 +1,90 @@
+use crate::{
+    bench::{self, Timings},
+    input::Input,
+    parse::ParseError,
+    solution::{Answer, Options, Part, Solution},
+    verbosity::{self, Verbosity},
+    year2023, year2024,
+};
+
+type SolveFn = fn(&Input, &[Part], &Options, &mut dyn FnMut(Part, Answer)) -> Result<(), ParseError>;
+
+/// A registered solution with its parsed input type erased.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    solve: SolveFn,
+    bench: fn(&Input, usize) -> Result<Timings, ParseError>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve::<S>,
+            bench: bench::run::<S>,
+        }
+    }
+
+    /// Parses the input once and reports the answer of every requested part as soon as it is known.
+    pub fn solve(
+        &self,
+        input: &Input,
+        parts: &[Part],
+        options: &Options,
+        on_answer: &mut dyn FnMut(Part, Answer),
+    ) -> Result<(), ParseError> {
+        (self.solve)(input, parts, options, on_answer)
+    }
+
+    pub fn bench(&self, input: &Input, iterations: usize) -> Result<Timings, ParseError> {
+        (self.bench)(input, iterations)
+    }
+}
+
+fn solve<S: Solution>(
+    input: &Input,
+    parts: &[Part],
+    options: &Options,
+    on_answer: &mut dyn FnMut(Part, Answer),
+) -> Result<(), ParseError> {
+    let input = S::parse(input)?;
+    for &part in parts {
+        let answer = S::part(&input, part);
+        if verbosity::enabled(Verbosity::Verbose) {
+            S::render(&input, part, options);
+        }
+        on_answer(part, answer);
+    }
+    Ok(())
+}
+
+/// All solutions, ordered by year and day.
+pub static DAYS: &[Day] = &[
+    Day::of::<year2023::day10::Day10>(),
+    Day::of::<year2024::day01::Day01>(),
+    Day::of::<year2024::day02::Day02>(),
+    Day::of::<year2024::day03::Day03>(),
+    Day::of::<year2024::day04::Day04>(),
+    Day::of::<year2024::day05::Day05>(),
+    Day::of::<year2024::day06::Day06>(),
+    Day::of::<year2024::day07::Day07>(),
+    Day::of::<year2024::day08::Day08>(),
+    Day::of::<year2024::day09::Day09>(),
+    Day::of::<year2024::day10::Day10>(),
+    Day::of::<year2024::day11::Day11>(),
+    Day::of::<year2024::day12::Day12>(),
+    Day::of::<year2024::day13::Day13>(),
+    Day::of::<year2024::day14::Day14>(),
+    Day::of::<year2024::day15::Day15>(),
+    Day::of::<year2024::day16::Day16>(),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn for_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
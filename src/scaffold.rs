@@ -0,0 +1,176 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+    Unexpected { path: PathBuf, expected: &'static str },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "'{}' already exists", path.display()),
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not access '{}': {}", path.display(), source)
+            }
+            ScaffoldError::Unexpected { path, expected } => {
+                write!(f, "could not find {} in '{}'", expected, path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Files touched while scaffolding a day.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Scaffold {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/// Creates the module, fixture and tests of a new day in the crate at `root`
+/// and registers it in the runner. Files are only written once all of them could be prepared.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Scaffold, ScaffoldError> {
+    let src = root.join("src");
+    let module = src.join(format!("year{}/day{:02}.rs", year, day));
+    if module.exists() {
+        return Err(ScaffoldError::Exists(module));
+    }
+
+    let mut writes = vec![(module, render(year, day))];
+
+    let fixture = root.join(format!("fixtures/{}_{:02}.txt", year, day));
+    if !fixture.exists() {
+        writes.push((fixture, String::new()));
+    }
+
+    let year_module = src.join(format!("year{}.rs", year));
+    let new_year = !year_module.exists();
+    let year_text = if new_year { String::new() } else { read(&year_module)? };
+    writes.push((year_module, insert_sorted(&year_text, &format!("pub mod day{:02};", day), "pub mod ")));
+
+    if new_year {
+        let lib = src.join("lib.rs");
+        let text = read(&lib)?;
+        let text = insert_sorted(&text, &format!("pub mod year{};", year), "pub mod ");
+        writes.push((lib, text));
+    }
+
+    let registry = src.join("registry.rs");
+    let text = register(&read(&registry)?, year, day)
+        .ok_or_else(|| ScaffoldError::Unexpected { path: registry.clone(), expected: "the list of days" })?;
+    writes.push((registry, text));
+
+    let mut scaffold = Scaffold::default();
+    for (path, text) in writes {
+        let existed = path.exists();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| ScaffoldError::Io { path: dir.to_path_buf(), source })?;
+        }
+        fs::write(&path, text).map_err(|source| ScaffoldError::Io { path: path.clone(), source })?;
+
+        if existed {
+            scaffold.updated.push(path);
+        } else {
+            scaffold.created.push(path);
+        }
+    }
+
+    Ok(scaffold)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io { path: path.to_path_buf(), source })
+}
+
+fn render(year: u16, day: u8) -> String {
+    TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DAY}}", &format!("{:02}", day))
+        .replace("{{DAY_NUMBER}}", &day.to_string())
+}
+
+/// Inserts `line` in order among the lines starting with `prefix`, or appends it if there are none.
+fn insert_sorted(text: &str, line: &str, prefix: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line) {
+        return text.to_string();
+    }
+
+    let index = match lines.iter().position(|l| l.starts_with(prefix) && *l > line) {
+        Some(index) => index,
+        None => lines.iter().rposition(|l| l.starts_with(prefix)).map_or(lines.len(), |i| i + 1),
+    };
+    lines.insert(index, line);
+
+    lines.join("\n") + "\n"
+}
+
+/// Adds the day to `DAYS` and imports its year module in the registry source.
+fn register(text: &str, year: u16, day: u8) -> Option<String> {
+    let entry = format!("    Day::of::<year{0}::day{1:02}::Day{1:02}>(),", year, day);
+    if !text.contains("pub static DAYS: &[Day] = &[") {
+        return None;
+    }
+    let text = insert_sorted(text, &entry, "    Day::of::<");
+
+    let imports = Regex::new(r"(?m)^( +)(year\d{4}(?:, year\d{4})*),$").unwrap();
+    let captures = imports.captures(&text)?;
+    let mut years: Vec<String> = captures[2].split(", ").map(str::to_string).collect();
+    let module = format!("year{}", year);
+    if !years.contains(&module) {
+        years.push(module);
+        years.sort();
+    }
+
+    let range = captures.get(0).unwrap().range();
+    let line = format!("{}{},", &captures[1], years.join(", "));
+    Some(format!("{}{}{}", &text[..range.start], line, &text[range.end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_the_template() {
+        let module = render(2024, 7);
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("include_str!(\"../../fixtures/2024_07.txt\")"));
+    }
+
+    #[test]
+    fn inserts_lines_in_order() {
+        let text = "use x;\n\npub mod day01;\npub mod day03;\n";
+        assert_eq!(insert_sorted(text, "pub mod day02;", "pub mod "), "use x;\n\npub mod day01;\npub mod day02;\npub mod day03;\n");
+        assert_eq!(insert_sorted(text, "pub mod day04;", "pub mod "), "use x;\n\npub mod day01;\npub mod day03;\npub mod day04;\n");
+        assert_eq!(insert_sorted("", "pub mod day01;", "pub mod "), "pub mod day01;\n");
+    }
+
+    #[test]
+    fn registers_days_of_new_years() {
+        let registry = register(include_str!("registry.rs"), 2025, 1).unwrap();
+
+        assert!(registry.contains("    year2023, year2024, year2025,\n"));
+        let entry = registry.find("    Day::of::<year2025::day01::Day01>(),\n").unwrap();
+        assert!(registry.rfind("Day::of::<year2024::").unwrap() < entry);
+    }
+}
@@ -0,0 +1,315 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Costs of the nodes reached by a search together with every predecessor
+/// on a cheapest path, so all shortest paths can be recovered.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    start: N,
+    reached: HashMap<N, Reached<N, C>>,
+    goals: Vec<N>,
+}
+
+#[derive(Debug, Clone)]
+struct Reached<N, C> {
+    cost: C,
+    predecessors: Vec<N>,
+}
+
+impl<N, C> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy,
+{
+    fn new(start: N, zero: C) -> Self {
+        let reached = HashMap::from([(start.clone(), Reached { cost: zero, predecessors: Vec::new() })]);
+        Paths { start, reached, goals: Vec::new() }
+    }
+
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// The cost of a cheapest path to `node`, if it was reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.reached.get(node).map(|reached| reached.cost)
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.reached.contains_key(node)
+    }
+
+    /// All reached nodes with their cost, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.reached.iter().map(|(node, reached)| (node, reached.cost))
+    }
+
+    /// The goals found with the lowest cost, in the order they were found.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The nodes directly before `node` on any of its cheapest paths.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.reached.get(node).map_or(&[], |reached| &reached.predecessors)
+    }
+
+    /// One cheapest path from the start to `node`, both inclusive.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.reached.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on any cheapest path from the start to one of `targets`.
+    pub fn on_shortest_paths<'a>(&self, targets: impl IntoIterator<Item = &'a N>) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        let mut stack: Vec<N> = targets.into_iter().filter(|n| self.contains(n)).cloned().collect();
+        let mut seen: HashSet<N> = stack.iter().cloned().collect();
+
+        while let Some(node) = stack.pop() {
+            for previous in self.predecessors(&node) {
+                if seen.insert(previous.clone()) {
+                    stack.push(previous.clone());
+                }
+            }
+        }
+
+        seen
+    }
+
+    /// The number of distinct cheapest paths from the start to `node`.
+    pub fn count_paths(&self, node: &N) -> u64 {
+        if !self.contains(node) {
+            return 0;
+        }
+
+        // post-order walk over the predecessors, so deep graphs do not overflow the stack
+        let mut counts: HashMap<N, u64> = HashMap::new();
+        let mut stack = vec![(node.clone(), false)];
+        while let Some((current, expanded)) = stack.pop() {
+            if counts.contains_key(&current) {
+                continue;
+            }
+
+            let predecessors = self.predecessors(&current);
+            if expanded || predecessors.is_empty() {
+                let count = if predecessors.is_empty() { 1 } else { predecessors.iter().map(|p| counts[p]).sum() };
+                counts.insert(current, count);
+            } else {
+                stack.push((current, true));
+                stack.extend(predecessors.iter().filter(|p| !counts.contains_key(*p)).map(|p| (p.clone(), false)));
+            }
+        }
+
+        counts[node]
+    }
+
+    /// Records reaching `next` from `node` at `cost`, returning whether it is cheaper than before.
+    fn relax(&mut self, node: &N, next: N, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.reached.entry(next) {
+            Entry::Vacant(entry) => {
+                entry.insert(Reached { cost, predecessors: vec![node.clone()] });
+                true
+            }
+            Entry::Occupied(mut entry) => {
+                let is_start = *entry.key() == self.start;
+                let reached = entry.get_mut();
+                if cost < reached.cost {
+                    *reached = Reached { cost, predecessors: vec![node.clone()] };
+                    true
+                } else {
+                    if cost == reached.cost && !is_start && !reached.predecessors.contains(node) {
+                        reached.predecessors.push(node.clone());
+                    }
+                    false
+                }
+            }
+        }
+    }
+}
+
+/// Visits every node reachable from `start` depth first and returns them in visiting order.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::from([start.clone()]);
+    let mut stack = vec![start];
+    let mut order = Vec::new();
+
+    while let Some(node) = stack.pop() {
+        for next in successors(&node) {
+            if visited.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+        order.push(node);
+    }
+
+    order
+}
+
+/// Finds the fewest steps from `start` to every reachable node.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Paths<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, steps)) = queue.pop_front() {
+        for next in successors(&node) {
+            if paths.relax(&node, next.clone(), steps + 1) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Finds the cheapest paths from `start`, stopping once every goal as cheap as
+/// the first one is found. Edge costs must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but expands the nodes in the order of their cost plus
+/// `heuristic`, which must never overestimate the remaining cost to a goal.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(start.clone(), C::default());
+    let mut best = None;
+
+    // the heap only holds indices into `nodes`, so nodes do not need to be ordered
+    let mut nodes = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((estimate, cost, index))) = heap.pop() {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+
+        let node = nodes[index].clone();
+        if paths.cost(&node).is_some_and(|known| cost > known) {
+            // a cheaper way to the node was found after this one was queued
+            continue;
+        }
+
+        if is_goal(&node) {
+            best = Some(cost);
+            paths.goals.push(node);
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if paths.relax(&node, next.clone(), next_cost) {
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Grid, Point};
+
+    const MAZE: &str = "\
+.....
+.###.
+...#.
+.#...
+";
+
+    fn open_neighbours(grid: &Grid<char>) -> impl FnMut(&Point) -> Vec<Point> + '_ {
+        |&position| {
+            grid.neighbours(position)
+                .filter(|(_, _, &c)| c == '.')
+                .map(|(_, next, _)| next)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn finds_all_shortest_paths_with_bfs() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = Point::new(4, 3);
+        let paths = bfs(Point::new(0, 0), open_neighbours(&grid));
+
+        assert_eq!(paths.cost(&end), Some(7));
+        assert_eq!(paths.count_paths(&end), 2);
+        assert_eq!(paths.path(&end).unwrap().len(), 8);
+        assert_eq!(paths.on_shortest_paths([&end]).len(), 14);
+        assert_eq!(paths.cost(&Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn visits_every_reachable_node_with_dfs() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let visited = dfs(Point::new(0, 0), open_neighbours(&grid));
+
+        assert_eq!(visited.len(), grid.values().filter(|&&c| c == '.').count());
+        assert_eq!(visited[0], Point::new(0, 0));
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = Point::new(4, 3);
+        // going down is three times as expensive
+        let weighted = |&position: &Point| {
+            open_neighbours(&grid)(&position)
+                .into_iter()
+                .map(move |next| (next, if next.y > position.y { 3 } else { 1 }))
+        };
+
+        let cheapest = dijkstra(Point::new(0, 0), weighted, |&p| p == end);
+        let guided = astar(Point::new(0, 0), weighted, |p| p.manhattan(end), |&p| p == end);
+
+        assert_eq!(cheapest.goals(), &[end]);
+        assert_eq!(cheapest.cost(&end), Some(13));
+        assert_eq!(guided.cost(&end), Some(13));
+        assert_eq!(cheapest.count_paths(&end), guided.count_paths(&end));
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::{self, Display};
+
+use crate::{input::Input, parse::ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(value),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    String(String),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::String(value) => write!(f, "{}", value),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(i64::try_from(value).expect("answer does not fit into an i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_string())
+    }
+}
+
+/// Settings from the command line that change what solutions render, never their answers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// The only antenna frequency the 2024 day 8 map shows.
+    pub frequency: Option<char>,
+}
+
+/// A puzzle solution. The input is parsed once and shared by both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    fn part(input: &Self::Input, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Prints a visualization of a solved part with `-v`, for solutions that take options.
+    fn render(_input: &Self::Input, _part: Part, _options: &Options) {}
+}
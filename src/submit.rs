@@ -0,0 +1,478 @@
+use std::{
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+use toml::{Table, Value};
+
+use crate::{
+    config::Config,
+    http::{self, HttpError},
+    solution::Part,
+};
+
+/// File the submission history is kept in when nothing else is configured.
+pub const DEFAULT_HISTORY_FILE: &str = "submissions.toml";
+
+const USER_AGENT: &str = "advent-of-code runner (aoc submit)";
+
+/// How the website responded to an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Not checked, because the previous answer was given too recently.
+    Wait,
+    /// Not checked, because the part is locked or already solved.
+    WrongLevel,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 6] = [
+        Verdict::Correct,
+        Verdict::TooHigh,
+        Verdict::TooLow,
+        Verdict::Wrong,
+        Verdict::Wait,
+        Verdict::WrongLevel,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+            Verdict::WrongLevel => "wrong level",
+        }
+    }
+
+    /// Whether the answer was checked and rejected, so sending it again is pointless.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An answer sent to the website, as recorded in the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub at: u64,
+    /// Seconds the website asked to wait before the next answer.
+    pub wait: Option<u64>,
+}
+
+impl Submission {
+    fn to_toml(&self) -> String {
+        let mut entry = format!(
+            "[[submission]]\nyear = {}\nday = {}\npart = {}\nanswer = {}\nverdict = {}\nat = {}\n",
+            self.year,
+            self.day,
+            self.part,
+            Value::String(self.answer.clone()),
+            Value::String(self.verdict.name().to_string()),
+            self.at
+        );
+        if let Some(wait) = self.wait {
+            entry += &format!("wait = {}\n", wait);
+        }
+        entry
+    }
+}
+
+/// Integer answers known to be wrong on either side of the right one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bounds {
+    /// The largest answer that was too low.
+    pub above: Option<i64>,
+    /// The smallest answer that was too high.
+    pub below: Option<i64>,
+}
+
+impl Bounds {
+    pub fn contains(&self, answer: i64) -> bool {
+        self.above.is_none_or(|above| answer > above) && self.below.is_none_or(|below| answer < below)
+    }
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.above, self.below) {
+            (Some(above), Some(below)) => write!(f, "above {} and below {}", above, below),
+            (Some(above), None) => write!(f, "above {}", above),
+            (None, Some(below)) => write!(f, "below {}", below),
+            (None, None) => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Why an answer is not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    AlreadyWrong { verdict: Verdict },
+    OutOfBounds { bounds: Bounds },
+    Wait { seconds: u64 },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "already solved with {}", answer),
+            Refusal::AlreadyWrong { verdict } => write!(f, "already submitted, the answer was {}", verdict),
+            Refusal::OutOfBounds { bounds } => write!(f, "the answer has to be {}", bounds),
+            Refusal::Wait { seconds } => write!(f, "wait {}s before submitting again", seconds),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Http(HttpError),
+    Io { path: PathBuf, source: io::Error },
+    Toml { path: PathBuf, source: toml::de::Error },
+    InvalidEntry { path: PathBuf, index: usize },
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted, {}", refusal),
+            SubmitError::Http(err) => write!(f, "{}", err),
+            SubmitError::Io { path, source } => {
+                write!(f, "could not access history in '{}': {}", path.display(), source)
+            }
+            SubmitError::Toml { path, source } => {
+                write!(f, "could not parse history in '{}': {}", path.display(), source)
+            }
+            SubmitError::InvalidEntry { path, index } => {
+                write!(f, "submission {} in '{}' is invalid", index + 1, path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SubmitError::Http(err) => Some(err),
+            SubmitError::Io { source, .. } => Some(source),
+            SubmitError::Toml { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<HttpError> for SubmitError {
+    fn from(err: HttpError) -> Self {
+        SubmitError::Http(err)
+    }
+}
+
+/// Every answer submitted so far, read from and appended to a file:
+///
+/// ```toml
+/// [[submission]]
+/// year = 2024
+/// day = 16
+/// part = 2
+/// answer = "512"
+/// verdict = "too low"
+/// at = 1734330000
+/// wait = 60
+/// ```
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Reads the history at `path`. It is fine if it does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        let submissions = match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text, path)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(source) => return Err(SubmitError::Io { path: path.to_path_buf(), source }),
+        };
+
+        Ok(History { path: path.to_path_buf(), submissions })
+    }
+
+    fn parse(text: &str, path: &Path) -> Result<Vec<Submission>, SubmitError> {
+        let table: Table = text.parse().map_err(|source| SubmitError::Toml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let entries = match table.get("submission") {
+            None => return Ok(Vec::new()),
+            Some(Value::Array(entries)) => entries,
+            Some(_) => return Err(SubmitError::InvalidEntry { path: path.to_path_buf(), index: 0 }),
+        };
+
+        entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                Self::parse_entry(entry).ok_or_else(|| SubmitError::InvalidEntry { path: path.to_path_buf(), index })
+            })
+            .collect()
+    }
+
+    fn parse_entry(entry: &Value) -> Option<Submission> {
+        let integer = |key: &str| entry.get(key)?.as_integer();
+        let string = |key: &str| entry.get(key)?.as_str();
+
+        let verdict = string("verdict")?;
+        Some(Submission {
+            year: integer("year")?.try_into().ok()?,
+            day: integer("day")?.try_into().ok()?,
+            part: Part::try_from(u8::try_from(integer("part")?).ok()?).ok()?,
+            answer: string("answer")?.to_string(),
+            verdict: Verdict::ALL.into_iter().find(|v| v.name() == verdict)?,
+            at: integer("at")?.try_into().ok()?,
+            wait: match entry.get("wait") {
+                None => None,
+                Some(wait) => Some(wait.as_integer()?.try_into().ok()?),
+            },
+        })
+    }
+
+    pub fn submissions(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| (s.year, s.day, s.part) == (year, day, part))
+    }
+
+    pub fn bounds(&self, year: u16, day: u8, part: Part) -> Bounds {
+        let mut bounds = Bounds::default();
+        for submission in self.submissions(year, day, part) {
+            let Ok(answer) = submission.answer.parse::<i64>() else {
+                continue;
+            };
+            match submission.verdict {
+                Verdict::TooLow => bounds.above = bounds.above.max(Some(answer)),
+                Verdict::TooHigh => bounds.below = Some(bounds.below.map_or(answer, |below| below.min(answer))),
+                _ => {}
+            }
+        }
+        bounds
+    }
+
+    /// Checks whether sending `answer` at `now` could tell us anything new.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        let submissions: Vec<_> = self.submissions(year, day, part).collect();
+
+        if let Some(correct) = submissions.iter().find(|s| s.verdict == Verdict::Correct) {
+            return Err(Refusal::AlreadySolved { answer: correct.answer.clone() });
+        }
+        if let Some(wrong) = submissions.iter().find(|s| s.answer == answer && s.verdict.is_wrong()) {
+            return Err(Refusal::AlreadyWrong { verdict: wrong.verdict });
+        }
+
+        let bounds = self.bounds(year, day, part);
+        if let Ok(answer) = answer.parse::<i64>() {
+            if !bounds.contains(answer) {
+                return Err(Refusal::OutOfBounds { bounds });
+            }
+        }
+
+        let waiting = submissions
+            .iter()
+            .filter_map(|s| Some(s.at + s.wait?))
+            .max()
+            .filter(|&until| until > now);
+        if let Some(until) = waiting {
+            return Err(Refusal::Wait { seconds: until - now });
+        }
+
+        Ok(())
+    }
+
+    /// Appends `submission` to the history file.
+    pub fn record(&mut self, submission: Submission) -> Result<(), SubmitError> {
+        let io_error = |source| SubmitError::Io { path: self.path.clone(), source };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+
+        let separator = if self.submissions.is_empty() { "" } else { "\n" };
+        write!(file, "{}{}", separator, submission.to_toml()).map_err(io_error)?;
+
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+/// Posts `answer` unless the history already knows how it would be judged,
+/// and records the response.
+pub fn submit(
+    config: &Config,
+    history: &mut History,
+    (year, day, part): (u16, u8, Part),
+    answer: &str,
+    now: u64,
+) -> Result<Submission, SubmitError> {
+    history.check(year, day, part, answer, now).map_err(SubmitError::Refused)?;
+
+    let level = part.to_string();
+    let body = http::post_form(
+        config,
+        &format!("/{}/day/{}/answer", year, day),
+        USER_AGENT,
+        &[("level", &level), ("answer", answer)],
+    )?;
+
+    let (verdict, wait) = judge(&body);
+    let submission = Submission {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        at: now,
+        wait,
+    };
+    history.record(submission.clone())?;
+
+    Ok(submission)
+}
+
+/// Reads the verdict and the requested wait from the page returned for an answer.
+fn judge(body: &str) -> (Verdict, Option<u64>) {
+    let verdict = if body.contains("That's the right answer") {
+        Verdict::Correct
+    } else if body.contains("You gave an answer too recently") {
+        Verdict::Wait
+    } else if body.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else if body.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if body.contains("your answer is too low") {
+        Verdict::TooLow
+    } else {
+        Verdict::Wrong
+    };
+
+    // "You have 1m 23s left to wait." after answering too early,
+    // "please wait 5 minutes before trying again." after a wrong answer
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let minutes = Regex::new(r"(?i)please wait (one|\d+) minutes? before trying again").unwrap();
+
+    let wait = if let Some(captures) = left.captures(body) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        Some(minutes * 60 + captures[2].parse::<u64>().unwrap())
+    } else {
+        minutes.captures(body).map(|captures| match &captures[1] {
+            "one" => 60,
+            minutes => minutes.parse::<u64>().unwrap() * 60,
+        })
+    };
+
+    (verdict, wait)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+    use crate::http::stub;
+
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low. \
+        Please wait one minute before trying again.</p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently. You have 41s left to wait.</p></article>";
+
+    fn temp_history(name: &str) -> History {
+        let path = std::env::temp_dir().join(format!("aoc-submit-{}-{}.toml", process::id(), name));
+        let _ = fs::remove_file(&path);
+        History::load(&path).unwrap()
+    }
+
+    #[test]
+    fn judges_responses() {
+        assert_eq!(judge(TOO_LOW), (Verdict::TooLow, Some(60)));
+        assert_eq!(judge(TOO_SOON), (Verdict::Wait, Some(41)));
+        assert_eq!(judge("You have 2m 5s left to wait. You gave an answer too recently"), (Verdict::Wait, Some(125)));
+        assert_eq!(judge("That's the right answer! You are one gold star closer."), (Verdict::Correct, None));
+        assert_eq!(judge("That's not the right answer. Please wait 5 minutes before trying again."), (Verdict::Wrong, Some(300)));
+    }
+
+    #[test]
+    fn records_submissions_and_never_repeats_wrong_answers() {
+        let (url, server) = stub::server(vec![(200, TOO_LOW), (200, "That's the right answer!")]);
+        let mut history = temp_history("repeat");
+        let puzzle = (2024, 16, Part::Two);
+
+        let first = submit(&stub::config(url.clone()), &mut history, puzzle, "45", 1000).unwrap();
+        assert_eq!((first.verdict, first.wait), (Verdict::TooLow, Some(60)));
+
+        // the mock has one response left, so refused answers must not reach it
+        let refused = |answer, now| match submit(&stub::config(url.clone()), &mut history.clone(), puzzle, answer, now) {
+            Err(SubmitError::Refused(refusal)) => refusal,
+            other => panic!("expected a refusal, got {:?}", other),
+        };
+        assert_eq!(refused("45", 2000), Refusal::AlreadyWrong { verdict: Verdict::TooLow });
+        assert_eq!(refused("12", 2000), Refusal::OutOfBounds { bounds: Bounds { above: Some(45), below: None } });
+        assert_eq!(refused("64", 1030), Refusal::Wait { seconds: 30 });
+
+        assert_eq!(submit(&stub::config(url), &mut history, puzzle, "64", 1060).unwrap().verdict, Verdict::Correct);
+        let forms: Vec<String> = server.join().unwrap().into_iter().map(|request| request.body).collect();
+        assert_eq!(forms, ["level=2&answer=45", "level=2&answer=64"]);
+
+        // everything survives a reload
+        let reloaded = History::load(&history.path).unwrap();
+        assert_eq!(reloaded.submissions(2024, 16, Part::Two).count(), 2);
+        assert_eq!(
+            reloaded.check(2024, 16, Part::Two, "70", 5000),
+            Err(Refusal::AlreadySolved { answer: "64".to_string() })
+        );
+        assert_eq!(reloaded.check(2024, 16, Part::One, "70", 5000), Ok(()));
+    }
+
+    #[test]
+    fn narrows_bounds_from_both_sides() {
+        let history = History {
+            path: PathBuf::new(),
+            submissions: [("10", Verdict::TooLow), ("90", Verdict::TooHigh), ("30", Verdict::TooLow), ("70", Verdict::TooHigh)]
+                .into_iter()
+                .map(|(answer, verdict)| Submission {
+                    year: 2024,
+                    day: 16,
+                    part: Part::One,
+                    answer: answer.to_string(),
+                    verdict,
+                    at: 0,
+                    wait: None,
+                })
+                .collect(),
+        };
+
+        let bounds = history.bounds(2024, 16, Part::One);
+        assert_eq!(bounds, Bounds { above: Some(30), below: Some(70) });
+        assert_eq!(bounds.to_string(), "above 30 and below 70");
+        assert!(bounds.contains(31) && !bounds.contains(70));
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much the solutions print besides their answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only the bare answers.
+    Quiet,
+    /// The answers of every part.
+    Normal,
+    /// Visualizations of the final state, e.g. the walked grid.
+    Verbose,
+    /// Tracing of every step.
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+impl Verbosity {
+    /// Maps the number of `-v` flags to a level, where `-q` wins.
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::Trace,
+        }
+    }
+}
+
+pub fn set(verbosity: Verbosity) {
+    LEVEL.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn get() -> Verbosity {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        1 => Verbosity::Normal,
+        2 => Verbosity::Verbose,
+        _ => Verbosity::Trace,
+    }
+}
+
+pub fn enabled(verbosity: Verbosity) -> bool {
+    get() >= verbosity
+}
+
+/// Prints a line like `println!`, but only with `-v` or more.
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::verbosity::enabled($crate::verbosity::Verbosity::Verbose) {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Prints a line like `println!`, but only with `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::verbosity::enabled($crate::verbosity::Verbosity::Trace) {
+            println!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quiet_wins_over_verbose_flags() {
+        assert_eq!(Verbosity::from_flags(true, 2), Verbosity::Quiet);
+        assert_eq!(Verbosity::from_flags(false, 0), Verbosity::Normal);
+        assert_eq!(Verbosity::from_flags(false, 1), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_flags(false, 3), Verbosity::Trace);
+    }
+}
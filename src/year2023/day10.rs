@@ -0,0 +1,246 @@
+use std::{collections::HashSet, fmt::Display};
+
+use colored::Colorize;
+
+use crate::{
+    input::Input,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace, verbose,
+    verbosity::{self, Verbosity},
+    Direction, Grid, Point,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tile {
+    NorthSouth,
+    EastWest,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Self::NorthSouth),
+            '-' => Ok(Self::EastWest),
+            'L' => Ok(Self::NorthEast),
+            'J' => Ok(Self::NorthWest),
+            '7' => Ok(Self::SouthWest),
+            'F' => Ok(Self::SouthEast),
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Start),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::NorthSouth => write!(f, "|"),
+            Tile::EastWest => write!(f, "-"),
+            Tile::NorthEast => write!(f, "L"),
+            Tile::NorthWest => write!(f, "J"),
+            Tile::SouthWest => write!(f, "7"),
+            Tile::SouthEast => write!(f, "F"),
+            Tile::Ground => write!(f, "."),
+            Tile::Start => write!(f, "S"),
+        }
+    }
+}
+
+impl Tile {
+    const PIPES: [Tile; 6] = [
+        Tile::NorthSouth,
+        Tile::EastWest,
+        Tile::NorthEast,
+        Tile::NorthWest,
+        Tile::SouthWest,
+        Tile::SouthEast,
+    ];
+
+    /// The pipe connecting exactly the given directions.
+    fn from_directions(directions: &[Direction]) -> Option<Tile> {
+        Tile::PIPES.into_iter().find(|pipe| {
+            directions.len() == 2 && Direction::CARDINAL.iter().all(|d| pipe.connects(d) == directions.contains(d))
+        })
+    }
+
+    /// The other end of a pipe entered through `from`.
+    fn exit(&self, from: Direction) -> Option<Direction> {
+        if !self.connects(&from) {
+            return None;
+        }
+        Direction::CARDINAL.into_iter().find(|d| *d != from && self.connects(d))
+    }
+
+    fn connects(&self, direction: &Direction) -> bool {
+        matches!(
+            (self, direction),
+            (Tile::NorthSouth, Direction::North | Direction::South)
+                | (Tile::EastWest, Direction::West | Direction::East)
+                | (Tile::NorthWest, Direction::North | Direction::West)
+                | (Tile::NorthEast, Direction::North | Direction::East)
+                | (Tile::SouthWest, Direction::West | Direction::South)
+                | (Tile::SouthEast, Direction::South | Direction::East)
+                | (
+                    Tile::Start,
+                    Direction::North | Direction::West | Direction::South | Direction::East
+                )
+        )
+    }
+}
+
+pub struct Field {
+    tiles: Grid<Tile>,
+    /// The positions along the loop, beginning with the start.
+    pipe_loop: Vec<Point>,
+}
+
+impl Field {
+    /// Replaces the start with the pipe connecting it to its neighbours and traces the loop through it.
+    fn new(mut tiles: Grid<Tile>, start: Point) -> Option<Self> {
+        let directions: Vec<Direction> = tiles
+            .neighbours(start)
+            .filter(|(direction, _, tile)| tile.connects(&direction.opposite()))
+            .map(|(direction, _, _)| direction)
+            .collect();
+        tiles[start] = Tile::from_directions(&directions)?;
+        verbose!("start: {} is {}", start, tiles[start]);
+
+        let mut pipe_loop = vec![start];
+        let mut direction = directions[0];
+        let mut position = start + direction.get_offset();
+        while position != start {
+            pipe_loop.push(position);
+            direction = tiles.get(position)?.exit(direction.opposite())?;
+            position += direction.get_offset();
+        }
+
+        Some(Field { tiles, pipe_loop })
+    }
+
+    /// The tiles inside the loop, found by counting how often a ray from the
+    /// west edge crosses pipes leading north.
+    fn enclosed(&self) -> HashSet<Point> {
+        let on_loop: HashSet<Point> = self.pipe_loop.iter().copied().collect();
+
+        let mut enclosed = HashSet::new();
+        for line in self.tiles.lines(Direction::East) {
+            let mut inside = false;
+            for (position, tile) in line {
+                if on_loop.contains(&position) {
+                    inside ^= tile.connects(&Direction::North);
+                } else if inside {
+                    enclosed.insert(position);
+                }
+            }
+        }
+
+        enclosed
+    }
+}
+
+fn print_field(field: &Field, enclosed: &HashSet<Point>) {
+    let on_loop: HashSet<Point> = field.pipe_loop.iter().copied().collect();
+
+    for y in 0..field.tiles.rows() {
+        for x in 0..field.tiles.columns() {
+            let position = Point::new(x as i64, y as i64);
+            if on_loop.contains(&position) {
+                print!("{}", field.tiles[position]);
+            } else if enclosed.contains(&position) {
+                print!("{}", "I".green());
+            } else {
+                print!("{}", ".".dimmed());
+            }
+        }
+        println!();
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input = Field;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        let text = input.text();
+        let tiles = input.grid_with(text, |c| Tile::try_from(c).ok())?;
+        let (Some(start), Some(offset)) = (tiles.find(&Tile::Start), text.find('S')) else {
+            return Err(ParseError::missing(text, "no start tile"));
+        };
+
+        Field::new(tiles, start).ok_or_else(|| {
+            ParseError::at(text, &text[offset..offset + 1], "start is not part of a closed loop of pipes")
+        })
+    }
+
+    fn part1(field: &Self::Input) -> Answer {
+        verbose!("columns: {}, rows: {}", field.tiles.columns(), field.tiles.rows());
+        trace!("loop: {:?}", field.pipe_loop);
+
+        (field.pipe_loop.len() / 2).into()
+    }
+
+    fn part2(field: &Self::Input) -> Answer {
+        let enclosed = field.enclosed();
+
+        if verbosity::enabled(Verbosity::Verbose) {
+            print_field(field, &enclosed);
+        }
+
+        enclosed.len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = include_str!("../../fixtures/2023_10_1.txt");
+    const EXAMPLE_2: &str = include_str!("../../fixtures/2023_10_2.txt");
+    const EXAMPLE_3: &str = include_str!("../../fixtures/2023_10_3.txt");
+    const EXAMPLE_4: &str = include_str!("../../fixtures/2023_10_4.txt");
+    const EXAMPLE_5: &str = include_str!("../../fixtures/2023_10_5.txt");
+
+    #[test]
+    fn part1_simple_loop() {
+        assert_eq!(Day10::part1(&Day10::parse(&Input::new(EXAMPLE_1)).unwrap()), Answer::Integer(4));
+    }
+
+    #[test]
+    fn part1_complex_loop() {
+        assert_eq!(Day10::part1(&Day10::parse(&Input::new(EXAMPLE_2)).unwrap()), Answer::Integer(8));
+    }
+
+    #[test]
+    fn part2_simple_enclosure() {
+        assert_eq!(Day10::part2(&Day10::parse(&Input::new(EXAMPLE_3)).unwrap()), Answer::Integer(4));
+    }
+
+    #[test]
+    fn part2_larger_enclosure() {
+        assert_eq!(Day10::part2(&Day10::parse(&Input::new(EXAMPLE_4)).unwrap()), Answer::Integer(8));
+    }
+
+    #[test]
+    fn part2_enclosure_with_junk_pipes() {
+        assert_eq!(Day10::part2(&Day10::parse(&Input::new(EXAMPLE_5)).unwrap()), Answer::Integer(10));
+    }
+
+    #[test]
+    fn infers_the_tile_under_the_start() {
+        let field = Day10::parse(&Input::new(EXAMPLE_5)).unwrap();
+        assert_eq!(field.tiles[Point::new(4, 0)], Tile::SouthWest);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    input::Input,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        let mut first_list = Vec::<i32>::new();
+        let mut second_list = Vec::<i32>::new();
+
+        for line in input.lines() {
+            let mut ids = line.split_whitespace();
+            let (Some(first), Some(second)) = (ids.next(), ids.next()) else {
+                return Err(ParseError::at(input.text(), line, "expected two location ids"));
+            };
+            if let Some(extra) = ids.next() {
+                return Err(ParseError::at(input.text(), extra, "expected only two location ids"));
+            }
+
+            let first: i32 = parse::number(input.text(), first)?;
+            let second: i32 = parse::number(input.text(), second)?;
+
+            first_list.push(first);
+            second_list.push(second);
+        }
+
+        first_list.sort();
+        second_list.sort();
+
+        Ok((first_list, second_list))
+    }
+
+    fn part1((first_list, second_list): &Self::Input) -> Answer {
+        let sum_distances: i32 = first_list
+            .iter()
+            .zip(second_list)
+            .map(|(first, second)| (first - second).abs())
+            .sum();
+
+        sum_distances.into()
+    }
+
+    fn part2((first_list, second_list): &Self::Input) -> Answer {
+        let mut total_similarity = 0;
+        for first in first_list {
+            let mut occasions = 0;
+            for second in second_list {
+                if first == second {
+                    occasions += 1;
+                }
+            }
+
+            let similarity = first * occasions;
+            total_similarity += similarity;
+        }
+
+        total_similarity.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_01.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day01::part1(&Day01::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(11));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day01::part2(&Day01::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(31));
+    }
+
+    #[test]
+    fn rejects_junk_around_the_ids() {
+        let err = Day01::parse(&Input::new("3   4\nx3   4y\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 0, "x3"));
+
+        let err = Day01::parse(&Input::new("3   4\n4   3 99\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 6, "99"));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    input::Input,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+fn is_report_safe(levels: Vec<i32>, index: usize, previous_diff: i32, tolerations: i32) -> bool {
+    if tolerations > 1 {
+        return false;
+    }
+
+    if index == levels.len()-1 {
+        return true;
+    }
+
+    let diff = levels[index] - levels[index+1];
+    let abs_diff = diff.abs();
+
+    if !(1..=3).contains(&abs_diff) || (diff < 0 && previous_diff > 0) || (diff > 0 && previous_diff < 0) {
+        for index_to_remove in 0..levels.len() {
+            let alternative_levels = [&levels[0..index_to_remove], &levels[index_to_remove+1..levels.len()]].concat();
+            if is_report_safe(alternative_levels, 0, 0, tolerations+1) {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    is_report_safe(levels, index+1, diff, tolerations)
+}
+
+fn count_safe_reports(reports: &[Vec<i32>], tolerations: i32) -> i32 {
+    let mut count_safe_reports = 0;
+
+    for levels in reports {
+        let safe = is_report_safe(levels.clone(), 0, 0, tolerations);
+
+        if safe {
+            count_safe_reports += 1;
+        }
+    }
+
+    count_safe_reports
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| line.split_whitespace().map(|x| parse::number(input.text(), x)).collect())
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        count_safe_reports(reports, 1).into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        // the Problem Dampener tolerates a single bad level
+        count_safe_reports(reports, 0).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_02.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day02::part1(&Day02::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day02::part2(&Day02::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(4));
+    }
+}
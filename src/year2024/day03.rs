@@ -0,0 +1,69 @@
+use regex::Regex;
+
+use crate::{
+    input::Input,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace,
+};
+
+fn sum_multiplications(input: &str, re: &Regex) -> i32 {
+    let mut sum = 0;
+
+    for captures in re.captures_iter(input) {
+        let x = captures.get(1).map(|x| x.as_str());
+        let y = captures.get(2).map(|y| y.as_str());
+
+        if let (Some(x), Some(y)) = (x, y) {
+            let x = x.parse::<i32>().unwrap();
+            let y = y.parse::<i32>().unwrap();
+            trace!("{} * {} = {}", x, y, x * y);
+            sum += x * y;
+        }
+    }
+
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    // the memory is scanned differently by both parts, so it is kept as is
+    type Input = String;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        Ok(input.text().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let re = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+        sum_multiplications(input, &re).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        // everything between a don't() and the next do() (or the end of the input) is skipped
+        let re = Regex::new(r"(?s)(?:don't\(\)(?:.*?do\(\)|.*?$))|mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+        sum_multiplications(input, &re).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = include_str!("../../fixtures/2024_03_1.txt");
+    const EXAMPLE_2: &str = include_str!("../../fixtures/2024_03_2.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day03::part1(&Day03::parse(&Input::new(EXAMPLE_1)).unwrap()), Answer::Integer(161));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day03::part2(&Day03::parse(&Input::new(EXAMPLE_2)).unwrap()), Answer::Integer(48));
+    }
+}
@@ -0,0 +1,130 @@
+use regex::Regex;
+
+use crate::{
+    input::Input,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    verbosity::{self, Verbosity},
+    Direction, Grid, Point,
+};
+
+fn count_xmas(grid: &Grid<char>) -> usize {
+    let re = Regex::new(r"XMAS").unwrap();
+
+    let mut output = Grid::new(grid.columns(), grid.rows(), '.');
+
+    let mut xmas_count = 0;
+
+    for direction in Direction::ALL {
+        for line in grid.lines(direction) {
+            let (orig_positions, row_str): (Vec<_>, String) = line.map(|(pos, &c)| (pos, c)).unzip();
+            for m in re.find_iter(row_str.as_str()) {
+                xmas_count += 1;
+                for &pos in &orig_positions[m.start()..m.end()] {
+                    output[pos] = grid[pos];
+                }
+            }
+        }
+    }
+
+    if verbosity::enabled(Verbosity::Verbose) {
+        print!("{}", output);
+    }
+
+    xmas_count
+}
+
+fn count_x_mas(grid: &Grid<char>) -> usize {
+    const KERNELS: [[[char; 3]; 3]; 4] = [
+        [
+            ['M', '.', 'S'],
+            ['.', 'A', '.'],
+            ['M', '.', 'S'],
+        ],
+        [
+            ['S', '.', 'M'],
+            ['.', 'A', '.'],
+            ['S', '.', 'M'],
+        ],
+        [
+            ['M', '.', 'M'],
+            ['.', 'A', '.'],
+            ['S', '.', 'S'],
+        ],
+        [
+            ['S', '.', 'S'],
+            ['.', 'A', '.'],
+            ['M', '.', 'M'],
+        ],
+    ];
+
+    let mut output = Grid::new(grid.columns(), grid.rows(), '.');
+
+    let mut xmas_count = 0;
+
+    for y in 1..grid.rows() as i64 - 1 {
+        for x in 1..grid.columns() as i64 - 1 {
+            let sample = [
+                [grid[Point::new(x - 1, y - 1)], '.', grid[Point::new(x + 1, y - 1)]],
+                ['.', grid[Point::new(x, y)], '.'],
+                [grid[Point::new(x - 1, y + 1)], '.', grid[Point::new(x + 1, y + 1)]],
+            ];
+
+            if KERNELS.contains(&sample) {
+                xmas_count += 1;
+                for (dy, row) in sample.iter().enumerate() {
+                    for (dx, &c) in row.iter().enumerate() {
+                        if c != '.' {
+                            let pos = Point::new(x + dx as i64 - 1, y + dy as i64 - 1);
+                            output[pos] = grid[pos];
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if verbosity::enabled(Verbosity::Verbose) {
+        print!("{}", output);
+    }
+
+    xmas_count
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        input.grid()
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        count_xmas(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        count_x_mas(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_04.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day04::part1(&Day04::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(18));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day04::part2(&Day04::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(9));
+    }
+}
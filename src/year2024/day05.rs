@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use colored::Colorize;
+
+use crate::{
+    input::Input,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    trace,
+    verbosity::{self, Verbosity},
+};
+
+fn topological_sort(page_number: i32, applicable_rules: &[(i32, i32)], visited: &mut HashMap<i32, bool>, stack: &mut Vec<i32>) {
+    visited.insert(page_number, true);
+
+    let applicable_xes: Vec<i32> = applicable_rules.iter()
+        .filter(|rule| rule.1 == page_number)
+        .map(|&(x, _)| x)
+        .collect();
+
+    for x in applicable_xes {
+        if !visited.get(&x).unwrap_or(&false) {
+            topological_sort(x, applicable_rules, visited, stack);
+        }
+    }
+
+    stack.push(page_number);
+}
+
+fn print_update(update: &[i32], correct: bool) {
+    for (i, page_number) in update.iter().enumerate() {
+        if i == update.len() / 2 && correct {
+            print!("{},", page_number.to_string().green());
+        } else if i == update.len() / 2 {
+            print!("{},", page_number.to_string().red());
+        } else if i == update.len() - 1 {
+            println!("{}", page_number);
+        } else {
+            print!("{},", page_number);
+        }
+    }
+}
+
+pub struct PrintQueue {
+    rules: Vec<(i32, i32)>,
+    updates: Vec<Vec<i32>>,
+}
+
+fn sum_middle_pages(PrintQueue { rules, updates }: &PrintQueue) -> (i32, i32) {
+    let mut sum = 0;
+    let mut sum_reordered = 0;
+
+    for update in updates.iter().cloned() {
+        let update_len = update.len();
+
+        let mut correct = true;
+
+        let mut applicable_rules = Vec::new();
+
+        for &rule in rules {
+            let x_index = update.iter().enumerate().find(|(_, &page_number)| page_number == rule.0).map(|(i, _)| i);
+            let y_index = update.iter().enumerate().find(|(_, &page_number)| page_number == rule.1).map(|(i, _)| i);
+
+            let (Some(x_index), Some(y_index)) = (x_index, y_index) else {
+                continue;
+            };
+
+            applicable_rules.push(rule);
+
+            if x_index > y_index {
+                correct = false;
+            }
+        }
+
+        let reordered_update: Vec<i32>;
+
+        if !correct {
+            let mut stack: Vec<i32> = Vec::new();
+            let mut visited: HashMap<i32, bool> = HashMap::new();
+            for &page_number in &update {
+                if !visited.get(&page_number).unwrap_or(&false) {
+                    topological_sort(page_number, &applicable_rules, &mut visited, &mut stack);
+                }
+            }
+
+            reordered_update = stack;
+            if update_len != reordered_update.len() {
+                trace!("lenghts do not match");
+            }
+        } else {
+            reordered_update = update;
+        }
+
+        if correct {
+            sum += reordered_update[update_len / 2];
+        } else {
+            sum_reordered += reordered_update[update_len / 2];
+        }
+
+        if verbosity::enabled(Verbosity::Trace) {
+            print_update(&reordered_update, correct);
+        }
+    }
+
+    (sum, sum_reordered)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input = PrintQueue;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        let mut blocks = input.blocks();
+        let (Some(rules), Some(updates)) = (blocks.next(), blocks.next()) else {
+            return Err(ParseError::missing(input.text(), "expected the rules and the updates separated by a blank line"));
+        };
+
+        let rules = rules
+            .lines()
+            .map(str::trim_end)
+            .map(|line| {
+                let Some((x, y)) = line.split_once('|') else {
+                    return Err(ParseError::at(input.text(), line, "expected a rule like '47|53'"));
+                };
+                Ok((parse::number(input.text(), x)?, parse::number(input.text(), y)?))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let updates = updates
+            .lines()
+            .map(str::trim_end)
+            .map(|line| line.split(',').map(|s| parse::number(input.text(), s)).collect())
+            .collect::<Result<_, _>>()?;
+
+        Ok(PrintQueue { rules, updates })
+    }
+
+    fn part1(print_queue: &Self::Input) -> Answer {
+        let (sum, _) = sum_middle_pages(print_queue);
+        sum.into()
+    }
+
+    fn part2(print_queue: &Self::Input) -> Answer {
+        let (_, sum_reordered) = sum_middle_pages(print_queue);
+        sum_reordered.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_05.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day05::part1(&Day05::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(143));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day05::part2(&Day05::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(123));
+    }
+}
@@ -0,0 +1,167 @@
+use colored::Colorize;
+
+use crate::{
+    input::Input,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace,
+    verbosity::{self, Verbosity},
+    Direction, Grid, Point,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guard {
+    position: Point,
+    direction: Direction,
+}
+
+fn print_grid(grid: &Grid<char>) {
+    for y in 0..grid.rows() {
+        for &c in grid.row(y) {
+            if c == 'O' {
+                print!("{}", c.to_string().red());
+            } else if Direction::try_from(c).is_ok() {
+                print!("{}", c.to_string().green());
+            } else {
+                print!("{}", c);
+            }
+        }
+        println!();
+    }
+}
+
+fn walk(grid: &mut Grid<char>, guard: Guard) {
+    let mut guard = guard.clone();
+    loop {
+        let next = guard.position + guard.direction.get_offset();
+        let Some(&c) = grid.get(next) else {
+            grid[guard.position] = 'X';
+            // path leads outside of the grid, so no loop
+            break;
+        };
+
+        // obstacle in front
+        if c == '#' {
+            guard.direction = guard.direction.rotate_right();
+        } else {
+            grid[guard.position] = 'X';
+            guard.position = next;
+        }
+    }
+}
+
+
+fn is_loop(grid: &Grid<char>, intial_guard: Guard, obstacle: Point) -> bool {
+    let mut guard = intial_guard.clone();
+    let mut path = Vec::new();
+
+    loop {
+        let next = guard.position + guard.direction.get_offset();
+        let Some(&c) = grid.get(next) else {
+            // path leads outside of the grid, so no loop
+            return false;
+        };
+
+        // obstacle in front
+        if c == '#' || next == obstacle {
+            if path.contains(&guard) {
+                return true;
+            }
+
+            path.push(guard.clone());
+
+            guard.direction = guard.direction.rotate_right();
+        } else {
+            guard.position = next;
+        }
+    }
+}
+
+fn count_loops(grid: &mut Grid<char>, guard: &Guard) -> u32 {
+    let mut count = 0;
+    let mut guard = guard.clone();
+    let mut path = Vec::new();
+    let mut obstacles = Vec::new();
+    loop {
+        let next = guard.position + guard.direction.get_offset();
+        let Some(&c) = grid.get(next) else {
+            break;
+        };
+
+        // obstacle in front
+        if c == '#' {
+            guard.direction = guard.direction.rotate_right();
+        } else {
+            if !path.contains(&next) && !obstacles.contains(&next) && is_loop(grid, guard.clone(), next) {
+                count += 1;
+                obstacles.push(next);
+                grid[next] = 'O';
+                trace!("{}", count);
+            }
+
+            guard.position = next;
+        }
+
+        path.push(guard.position);
+    }
+    count
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input = (Grid<char>, Guard);
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        let grid = input.grid()?;
+
+        let (position, direction) = grid
+            .iter()
+            .find_map(|(pos, &c)| Direction::try_from(c).ok().map(|direction| (pos, direction)))
+            .ok_or_else(|| ParseError::missing(input.text(), "no guard on the map"))?;
+
+        Ok((grid, Guard { position, direction }))
+    }
+
+    fn part1((grid, guard): &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+
+        walk(&mut grid, guard.clone());
+        if verbosity::enabled(Verbosity::Verbose) {
+            print_grid(&grid);
+        }
+
+        grid.find_all(&'X').count().into()
+    }
+
+    fn part2((grid, guard): &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+
+        let loop_count = count_loops(&mut grid, guard);
+        if verbosity::enabled(Verbosity::Verbose) {
+            print_grid(&grid);
+        }
+
+        loop_count.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_06.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day06::part1(&Day06::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(41));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day06::part2(&Day06::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(6));
+    }
+}
@@ -0,0 +1,100 @@
+use itertools::Itertools;
+
+use crate::{
+    input::Input,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    verbosity::{self, Verbosity},
+};
+
+fn calibration_result(equations: &[(u64, Vec<u64>)], possible_operators: &[char]) -> u64 {
+    let mut sum = 0;
+
+    for (test_value, numbers) in equations {
+        let test_value = *test_value;
+        let combinations = (0..numbers.len()-1).map(|_| possible_operators.iter()).multi_cartesian_product().collect_vec();
+        for operators in combinations {
+            let mut working_numbers = numbers.clone();
+            for i in 0..operators.len() {
+                match operators[i] {
+                    '+' => working_numbers[i+1] += working_numbers[i],
+                    '*' => working_numbers[i+1] *= working_numbers[i],
+                    '|' => working_numbers[i+1] = working_numbers[i] * u64::pow(10, (working_numbers[i + 1].to_string().len()).try_into().unwrap()) + working_numbers[i + 1],
+                    _ => panic!("Invalid operator"),
+
+                }
+            }
+
+            if test_value == working_numbers[working_numbers.len() - 1] {
+                if verbosity::enabled(Verbosity::Trace) {
+                    for i in 0..operators.len() {
+                        print!("{} {} ", numbers[i], operators[i]);
+                    }
+                    println!("{} = {} matches", numbers[operators.len()], working_numbers[operators.len()]);
+                }
+                sum += test_value;
+                // one combination matches, so no need to test the others
+                break;
+            }
+        }
+    }
+
+    sum
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let Some((test_value, numbers)) = line.split_once(':') else {
+                    return Err(ParseError::at(input.text(), line, "expected a test value followed by ':'"));
+                };
+                let test_value = parse::number(input.text(), test_value)?;
+                let numbers: Vec<u64> = numbers.split_whitespace().map(|s| parse::number(input.text(), s)).collect::<Result<_, _>>()?;
+                if numbers.is_empty() {
+                    return Err(ParseError::at(input.text(), line, "expected at least one number after ':'"));
+                }
+                Ok((test_value, numbers))
+            })
+            .collect()
+    }
+
+    fn part1(equations: &Self::Input) -> Answer {
+        calibration_result(equations, &['+', '*']).into()
+    }
+
+    fn part2(equations: &Self::Input) -> Answer {
+        calibration_result(equations, &['+', '*', '|']).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_07.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day07::part1(&Day07::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(3749));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day07::part2(&Day07::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(11387));
+    }
+
+    #[test]
+    fn rejects_equations_without_numbers() {
+        let err = Day07::parse(&Input::new("3267: 81 40 27\n190:\n")).unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (1, "190:"));
+    }
+}
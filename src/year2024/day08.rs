@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use colored::{Color, Colorize};
+use itertools::Itertools;
+
+use crate::{
+    input::Input,
+    math,
+    parse::ParseError,
+    solution::{Answer, Options, Part, Solution},
+    Grid, Point,
+};
+
+/// Which points on the line through two antennas of the same frequency are antinodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// One antinode beyond each antenna, twice as far from one as from the other.
+    Paired,
+    /// Every grid point on the line, including the antennas themselves.
+    Resonant,
+}
+
+fn antennas(grid: &Grid<char>) -> BTreeMap<char, Vec<Point>> {
+    let mut frequencies: BTreeMap<char, Vec<Point>> = BTreeMap::new();
+    for (pos, &c) in grid.iter() {
+        if c.is_ascii_alphanumeric() {
+            frequencies.entry(c).or_default().push(pos);
+        }
+    }
+    frequencies
+}
+
+fn antinodes_of(grid: &Grid<char>, first: Point, second: Point, mode: Mode) -> Vec<Point> {
+    let distance = second - first;
+    match mode {
+        Mode::Paired => [first - distance, second + distance]
+            .into_iter()
+            .filter(|&antinode| grid.is_in_bounds(antinode))
+            .collect(),
+        Mode::Resonant => {
+            // the smallest whole step along the line through both antennas
+            let divisor = math::gcd(distance.x, distance.y);
+            let step = Point::new(distance.x / divisor, distance.y / divisor);
+
+            let mut antinodes = Vec::new();
+            for step in [step, -step] {
+                let mut antinode = first;
+                while grid.is_in_bounds(antinode) {
+                    antinodes.push(antinode);
+                    antinode += step;
+                }
+            }
+            antinodes
+        }
+    }
+}
+
+/// Every antinode with the frequencies producing it.
+fn find_antinodes(grid: &Grid<char>, antennas: &BTreeMap<char, Vec<Point>>, mode: Mode) -> HashMap<Point, BTreeSet<char>> {
+    let mut antinodes: HashMap<Point, BTreeSet<char>> = HashMap::new();
+    for (&frequency, positions) in antennas {
+        for (&first, &second) in positions.iter().tuple_combinations() {
+            for antinode in antinodes_of(grid, first, second, mode) {
+                antinodes.entry(antinode).or_default().insert(frequency);
+            }
+        }
+    }
+    antinodes
+}
+
+const PALETTE: [Color; 12] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+];
+
+/// Prints the map with the antennas and antinodes of every frequency in its own
+/// colour, or only those of `only`. Antinodes shared by several frequencies are
+/// white and antennas which are antinodes themselves are underlined.
+fn print_antinodes(
+    grid: &Grid<char>,
+    antennas: &BTreeMap<char, Vec<Point>>,
+    antinodes: &HashMap<Point, BTreeSet<char>>,
+    only: Option<char>,
+) {
+    let colors: HashMap<char, Color> =
+        antennas.keys().enumerate().map(|(i, &frequency)| (frequency, PALETTE[i % PALETTE.len()])).collect();
+    let shown = |frequency: &char| only.is_none_or(|only| only == *frequency);
+
+    for y in 0..grid.rows() {
+        for x in 0..grid.columns() {
+            let position = Point::new(x as i64, y as i64);
+            let producers: Vec<char> = antinodes.get(&position).into_iter().flatten().copied().filter(shown).collect();
+
+            let c = grid[position];
+            let cell = if colors.contains_key(&c) {
+                if !shown(&c) {
+                    c.to_string().dimmed()
+                } else if producers.is_empty() {
+                    c.to_string().color(colors[&c]).bold()
+                } else {
+                    c.to_string().color(colors[&c]).bold().underline()
+                }
+            } else {
+                match producers[..] {
+                    [] => c.to_string().dimmed(),
+                    [frequency] => "#".color(colors[&frequency]),
+                    _ => "#".white().bold(),
+                }
+            };
+            print!("{}", cell);
+        }
+        println!();
+    }
+
+    for (frequency, positions) in antennas.iter().filter(|(frequency, _)| shown(frequency)) {
+        let count = antinodes.values().filter(|producers| producers.contains(frequency)).count();
+        println!(
+            "{}: {} antennas, {} antinodes",
+            frequency.to_string().color(colors[frequency]).bold(),
+            positions.len(),
+            count
+        );
+    }
+}
+
+/// The frequency to render alone, unless the map has no antennas of it.
+fn frequency_filter(frequency: Option<char>, antennas: &BTreeMap<char, Vec<Point>>) -> Result<Option<char>, String> {
+    match frequency {
+        Some(frequency) if !antennas.contains_key(&frequency) => {
+            Err(format!("no antennas of frequency '{}' on the map", frequency))
+        }
+        frequency => Ok(frequency),
+    }
+}
+
+fn count_antinodes(grid: &Grid<char>, mode: Mode) -> usize {
+    find_antinodes(grid, &antennas(grid), mode).len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        input.grid()
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        count_antinodes(grid, Mode::Paired).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        count_antinodes(grid, Mode::Resonant).into()
+    }
+
+    fn render(grid: &Self::Input, part: Part, options: &Options) {
+        let mode = match part {
+            Part::One => Mode::Paired,
+            Part::Two => Mode::Resonant,
+        };
+        let antennas = antennas(grid);
+        let antinodes = find_antinodes(grid, &antennas, mode);
+
+        let only = frequency_filter(options.frequency, &antennas).unwrap_or_else(|err| {
+            eprintln!("warning: {}, showing all frequencies", err);
+            None
+        });
+        print_antinodes(grid, &antennas, &antinodes, only);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_08.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day08::part1(&Day08::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(14));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day08::part2(&Day08::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(34));
+    }
+
+    #[test]
+    fn handles_maps_wider_than_tall() {
+        let grid = Day08::parse(&Input::new("..........\n..a.a.....\n..........\n")).unwrap();
+
+        assert_eq!(
+            antinodes_of(&grid, Point::new(2, 1), Point::new(4, 1), Mode::Paired),
+            vec![Point::new(0, 1), Point::new(6, 1)]
+        );
+        assert_eq!(count_antinodes(&grid, Mode::Paired), 2);
+        assert_eq!(count_antinodes(&grid, Mode::Resonant), 10);
+    }
+
+    #[test]
+    fn checks_the_frequency_filter() {
+        let grid = Day08::parse(&Input::new(EXAMPLE)).unwrap();
+        let antennas = antennas(&grid);
+
+        assert_eq!(frequency_filter(None, &antennas), Ok(None));
+        assert_eq!(frequency_filter(Some('A'), &antennas), Ok(Some('A')));
+        assert_eq!(frequency_filter(Some('z'), &antennas), Err("no antennas of frequency 'z' on the map".to_string()));
+    }
+}
@@ -0,0 +1,553 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+    fmt,
+};
+
+use crate::{
+    input::Input,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    verbosity::{self, Verbosity},
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Block {
+    Space,
+    File(usize),
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Block::Space => write!(f, "."),
+            Block::File(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FsObject {
+    Space(usize, usize),        // start, length
+    File(usize, usize, usize),  // id, start, length
+}
+
+impl fmt::Display for FsObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FsObject::Space(_, space_length) => {
+                for _ in 0..*space_length {
+                    write!(f, ".")?;
+                }
+                Ok(())
+            },
+            FsObject::File(id, _, file_length) => {
+                for _ in 0..*file_length {
+                    write!(f, "{}", id)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+fn print_fs(fs: &[Block]) {
+    for block in fs {
+        print!("{}", block);
+    }
+    println!();
+}
+
+/// Lays out the files block by block, with space wherever no file is.
+fn to_blocks(fs_objects: &[FsObject]) -> Vec<Block> {
+    let mut fs = Vec::new();
+    for fs_object in fs_objects {
+        let (block, start, length) = match *fs_object {
+            FsObject::Space(start, length) => (Block::Space, start, length),
+            FsObject::File(id, start, length) => (Block::File(id), start, length),
+        };
+        if fs.len() < start + length {
+            fs.resize(start + length, Block::Space);
+        }
+        fs[start..start + length].fill(block);
+    }
+    fs
+}
+
+fn parse_disk_map(input: &str) -> Result<Vec<FsObject>, ParseError> {
+    let mut fs_objects = Vec::new();
+    let mut block_index = 0;
+    for (i, c) in input.trim_end().char_indices() {
+        if !c.is_ascii_digit() {
+            return Err(ParseError::at(input, &input[i..i + c.len_utf8()], "expected a digit"));
+        }
+
+        let number = (c as u8 - b'0') as usize;
+        if i % 2 == 0 {
+            fs_objects.push(FsObject::File(i / 2, block_index, number));
+        } else {
+            fs_objects.push(FsObject::Space(block_index, number));
+        }
+        block_index += number;
+    }
+
+    Ok(fs_objects)
+}
+
+/// The files on a disk, each made of one or more fragments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMap {
+    /// The number of blocks on the disk.
+    size: usize,
+    /// The fragments as `(id, start, length)`, ordered by start.
+    fragments: Vec<(usize, usize, usize)>,
+}
+
+impl DiskMap {
+    pub fn new(fs_objects: &[FsObject]) -> Self {
+        let mut disk = DiskMap { size: 0, fragments: Vec::new() };
+        for fs_object in fs_objects {
+            match *fs_object {
+                FsObject::Space(start, length) => disk.size = disk.size.max(start + length),
+                FsObject::File(id, start, length) => {
+                    disk.size = disk.size.max(start + length);
+                    disk.fragments.push((id, start, length));
+                }
+            }
+        }
+        disk.normalize();
+        disk
+    }
+
+    /// The files and the space between them, from the start of the disk to its end.
+    pub fn fs_objects(&self) -> Vec<FsObject> {
+        let mut fs_objects = Vec::new();
+        let mut end = 0;
+        for &(id, start, length) in &self.fragments {
+            if start > end {
+                fs_objects.push(FsObject::Space(end, start - end));
+            }
+            fs_objects.push(FsObject::File(id, start, length));
+            end = start + length;
+        }
+        if self.size > end {
+            fs_objects.push(FsObject::Space(end, self.size - end));
+        }
+        fs_objects
+    }
+
+    /// The free spans as `(start, length)`, ordered by start.
+    pub fn free_spans(&self) -> Vec<(usize, usize)> {
+        self.fs_objects()
+            .into_iter()
+            .filter_map(|fs_object| match fs_object {
+                FsObject::Space(start, length) => Some((start, length)),
+                FsObject::File(..) => None,
+            })
+            .collect()
+    }
+
+    pub fn checksum(&self) -> usize {
+        self.fragments
+            .iter()
+            // id * (start + start + 1 + ... + start + length - 1)
+            .map(|&(id, start, length)| id * (start * length + length * length.saturating_sub(1) / 2))
+            .sum()
+    }
+
+    pub fn fragmentation(&self) -> Fragmentation {
+        let files = self.fragments.iter().map(|&(id, _, _)| id + 1).max().unwrap_or(0);
+        let mut fragments_per_file = vec![0; files];
+        for &(id, _, length) in &self.fragments {
+            if length > 0 {
+                fragments_per_file[id] += 1;
+            }
+        }
+
+        Fragmentation {
+            fragments_per_file,
+            largest_free_span: self.free_spans().into_iter().map(|(_, length)| length).max().unwrap_or(0),
+        }
+    }
+
+    /// A copy of the disk compacted with `strategy`.
+    pub fn compacted(&self, strategy: &dyn Strategy) -> DiskMap {
+        let mut disk = self.clone();
+        strategy.compact(&mut disk);
+        disk.normalize();
+        disk
+    }
+
+    /// Sorts the fragments and joins neighbouring fragments of the same file.
+    fn normalize(&mut self) {
+        self.fragments.sort_unstable_by_key(|&(_, start, _)| start);
+
+        let mut joined: Vec<(usize, usize, usize)> = Vec::with_capacity(self.fragments.len());
+        for &(id, start, length) in &self.fragments {
+            match joined.last_mut() {
+                Some((last_id, last_start, last_length)) if *last_id == id && *last_start + *last_length == start => {
+                    *last_length += length;
+                }
+                _ => joined.push((id, start, length)),
+            }
+        }
+        self.fragments = joined;
+    }
+}
+
+/// How scattered the files and the free space of a disk are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragmentation {
+    /// The number of fragments of every file, indexed by id.
+    pub fragments_per_file: Vec<usize>,
+    pub largest_free_span: usize,
+}
+
+impl fmt::Display for Fragmentation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fragmented = self.fragments_per_file.iter().filter(|&&fragments| fragments > 1).count();
+        let most = self.fragments_per_file.iter().max().unwrap_or(&0);
+        write!(
+            f,
+            "{} of {} files fragmented, at most {} fragments, largest free span {}",
+            fragmented,
+            self.fragments_per_file.len(),
+            most,
+            self.largest_free_span
+        )
+    }
+}
+
+/// A way of moving the files on a disk towards its start.
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+
+    fn compact(&self, disk: &mut DiskMap);
+}
+
+/// Moves single blocks from the end of the disk into the leftmost free space,
+/// splitting files into several fragments.
+pub struct Fragmenting;
+
+impl Strategy for Fragmenting {
+    fn name(&self) -> &'static str {
+        "fragmenting"
+    }
+
+    fn compact(&self, disk: &mut DiskMap) {
+        let free_spans = disk.free_spans();
+        let mut fragments = std::mem::take(&mut disk.fragments);
+        let mut moved = Vec::new();
+
+        for (mut space_start, mut space_length) in free_spans {
+            while space_length > 0 {
+                let Some((id, file_start, file_length)) = fragments.last_mut() else {
+                    break;
+                };
+                if *file_start < space_start {
+                    break;
+                }
+
+                // take the blocks off the end of the last fragment
+                let count = space_length.min(*file_length);
+                moved.push((*id, space_start, count));
+                space_start += count;
+                space_length -= count;
+                *file_length -= count;
+                if *file_length == 0 {
+                    fragments.pop();
+                }
+            }
+        }
+
+        fragments.extend(moved);
+        disk.fragments = fragments;
+    }
+}
+
+/// A strategy which moves whole files, or fragments of already fragmented files,
+/// into a single free span on their left. The space they leave behind can be
+/// used by the fragments moved after them.
+pub trait Fit {
+    fn name(&self) -> &'static str;
+
+    /// Picks the span to move into from the leftmost fitting span of every
+    /// length, given as `(start, length)` ordered by length.
+    fn choose(&self, candidates: &[(usize, usize)]) -> Option<(usize, usize)>;
+}
+
+impl<F: Fit> Strategy for F {
+    fn name(&self) -> &'static str {
+        Fit::name(self)
+    }
+
+    /// Moves every fragment once, in decreasing id order.
+    fn compact(&self, disk: &mut DiskMap) {
+        let mut free = FreeSpace::new(disk.free_spans());
+
+        disk.fragments.sort_unstable_by_key(|&(id, start, _)| (id, start));
+        for (_, file_start, file_length) in disk.fragments.iter_mut().rev() {
+            let candidates = free.candidates(*file_length, *file_start);
+            let Some((space_start, space_length)) = self.choose(&candidates) else {
+                continue;
+            };
+
+            free.allocate(space_start, space_length, *file_length);
+            free.release(*file_start, *file_length);
+            *file_start = space_start;
+        }
+    }
+}
+
+/// The free spans of a disk, which can be looked up by length and are joined
+/// with their neighbours when space is released.
+struct FreeSpace {
+    /// The length of every free span by its start.
+    spans: BTreeMap<usize, usize>,
+    /// The starts of the free spans by their length, so the leftmost one of
+    /// every length is on top. Starts of spans which have since been used or
+    /// joined are only dropped once they reach the top.
+    by_length: BTreeMap<usize, BinaryHeap<Reverse<usize>>>,
+}
+
+impl FreeSpace {
+    fn new(spans: Vec<(usize, usize)>) -> Self {
+        let mut free = FreeSpace { spans: BTreeMap::new(), by_length: BTreeMap::new() };
+        for (start, length) in spans {
+            free.insert(start, length);
+        }
+        free
+    }
+
+    fn insert(&mut self, start: usize, length: usize) {
+        if length > 0 {
+            self.spans.insert(start, length);
+            self.by_length.entry(length).or_default().push(Reverse(start));
+        }
+    }
+
+    /// The leftmost span of every length of at least `length` starting before `before`,
+    /// as `(start, length)` ordered by length.
+    fn candidates(&mut self, length: usize, before: usize) -> Vec<(usize, usize)> {
+        let mut candidates = Vec::new();
+        let mut unused = Vec::new();
+        for (&span_length, starts) in self.by_length.range_mut(length..) {
+            while let Some(&Reverse(start)) = starts.peek() {
+                if self.spans.get(&start) == Some(&span_length) {
+                    if start < before {
+                        candidates.push((start, span_length));
+                    }
+                    break;
+                }
+                starts.pop();
+            }
+            if starts.is_empty() {
+                unused.push(span_length);
+            }
+        }
+        for span_length in unused {
+            self.by_length.remove(&span_length);
+        }
+        candidates
+    }
+
+    /// Uses the first `used` blocks of the span at `start`.
+    fn allocate(&mut self, start: usize, length: usize, used: usize) {
+        self.spans.remove(&start);
+        self.insert(start + used, length - used);
+    }
+
+    /// Frees `length` blocks at `start`, joining them with the free spans on either side.
+    fn release(&mut self, mut start: usize, mut length: usize) {
+        if let Some((&before, &before_length)) = self.spans.range(..start).next_back() {
+            if before + before_length == start {
+                self.spans.remove(&before);
+                start = before;
+                length += before_length;
+            }
+        }
+        if let Some(after_length) = self.spans.remove(&(start + length)) {
+            length += after_length;
+        }
+        self.insert(start, length);
+    }
+}
+
+/// Moves every file into the leftmost span it fits into.
+pub struct FirstFit;
+
+impl Fit for FirstFit {
+    fn name(&self) -> &'static str {
+        "first fit"
+    }
+
+    fn choose(&self, candidates: &[(usize, usize)]) -> Option<(usize, usize)> {
+        candidates.iter().copied().min_by_key(|&(start, _)| start)
+    }
+}
+
+/// Moves every file into the smallest span it fits into.
+pub struct BestFit;
+
+impl Fit for BestFit {
+    fn name(&self) -> &'static str {
+        "best fit"
+    }
+
+    fn choose(&self, candidates: &[(usize, usize)]) -> Option<(usize, usize)> {
+        candidates.first().copied()
+    }
+}
+
+/// Moves every file into the largest span it fits into.
+pub struct WorstFit;
+
+impl Fit for WorstFit {
+    fn name(&self) -> &'static str {
+        "worst fit"
+    }
+
+    fn choose(&self, candidates: &[(usize, usize)]) -> Option<(usize, usize)> {
+        candidates.last().copied()
+    }
+}
+
+pub const STRATEGIES: [&dyn Strategy; 4] = [&Fragmenting, &FirstFit, &BestFit, &WorstFit];
+
+fn compact(disk: &DiskMap, strategy: &dyn Strategy) -> usize {
+    if verbosity::enabled(Verbosity::Verbose) {
+        print_fs(&to_blocks(&disk.fs_objects()));
+    }
+
+    let compacted = disk.compacted(strategy);
+    if verbosity::enabled(Verbosity::Verbose) {
+        print_fs(&to_blocks(&compacted.fs_objects()));
+        println!("{}: {}", strategy.name(), compacted.fragmentation());
+    }
+
+    compacted.checksum()
+}
+
+/// Prints the checksum and fragmentation every strategy leaves the disk with.
+fn compare_strategies(disk: &DiskMap) {
+    for strategy in STRATEGIES {
+        let compacted = disk.compacted(strategy);
+        println!("{:<12} checksum {:<16} {}", strategy.name(), compacted.checksum(), compacted.fragmentation());
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Input = DiskMap;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        Ok(DiskMap::new(&parse_disk_map(input.text())?))
+    }
+
+    fn part1(disk: &Self::Input) -> Answer {
+        compact(disk, &Fragmenting).into()
+    }
+
+    fn part2(disk: &Self::Input) -> Answer {
+        let checksum = compact(disk, &FirstFit);
+        if verbosity::enabled(Verbosity::Verbose) {
+            compare_strategies(disk);
+        }
+        checksum.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_09.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day09::part1(&Day09::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(1928));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day09::part2(&Day09::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(2858));
+    }
+
+    fn layout(disk: &DiskMap) -> String {
+        to_blocks(&disk.fs_objects()).iter().map(Block::to_string).collect()
+    }
+
+    #[test]
+    fn only_moves_files_to_the_left() {
+        let disk = Day09::parse(&Input::new("12345")).unwrap();
+
+        assert_eq!(layout(&disk.compacted(&Fragmenting)), "022111222......");
+        assert_eq!(Day09::part1(&disk), Answer::Integer(60));
+
+        // neither file fits into a gap on its left
+        assert_eq!(layout(&disk.compacted(&FirstFit)), "0..111....22222");
+        assert_eq!(Day09::part2(&disk), Answer::Integer(132));
+    }
+
+    #[test]
+    fn fits_whole_files_by_strategy() {
+        let disk = Day09::parse(&Input::new("1311151")).unwrap();
+        assert_eq!(layout(&disk), "0...1.2.....3");
+
+        let first_fit = disk.compacted(&FirstFit);
+        assert_eq!(layout(&first_fit), "0321.........");
+        assert_eq!(first_fit.fragmentation().largest_free_span, 9);
+
+        let best_fit = disk.compacted(&BestFit);
+        assert_eq!(layout(&best_fit), "021..3.......");
+        assert_eq!(best_fit.fragmentation().largest_free_span, 7);
+
+        let worst_fit = disk.compacted(&WorstFit);
+        assert_eq!(layout(&worst_fit), "021....3.....");
+        assert_eq!(worst_fit.fragmentation().largest_free_span, 5);
+    }
+
+    #[test]
+    fn counts_fragments() {
+        let disk = Day09::parse(&Input::new(EXAMPLE)).unwrap();
+        assert_eq!(disk.fragmentation().fragments_per_file, vec![1; 10]);
+
+        let fragmented = disk.compacted(&Fragmenting).fragmentation();
+        assert_eq!(fragmented.fragments_per_file, vec![1, 1, 1, 1, 1, 1, 3, 1, 2, 1]);
+        assert_eq!(fragmented.largest_free_span, 14);
+        assert_eq!(fragmented.to_string(), "2 of 10 files fragmented, at most 3 fragments, largest free span 14");
+    }
+
+    #[test]
+    fn fits_fragments_of_fragmented_disks() {
+        let disk = DiskMap::new(&[
+            FsObject::File(0, 0, 1),
+            FsObject::File(2, 2, 1),
+            FsObject::File(1, 5, 2),
+            FsObject::File(1, 8, 1),
+        ]);
+        assert_eq!(layout(&disk), "0.2..11.1");
+
+        // moving 2 first joins the space it leaves with the gap behind it, so 1 fits there
+        let first_fit = disk.compacted(&FirstFit);
+        assert_eq!(layout(&first_fit), "02111....");
+        assert_eq!(first_fit.fragmentation().fragments_per_file, [1, 1, 1]);
+
+        let best_fit = disk.compacted(&BestFit);
+        assert_eq!(layout(&best_fit), "0211...1.");
+        assert_eq!(best_fit.fragmentation().fragments_per_file, [1, 2, 1]);
+
+        let worst_fit = disk.compacted(&WorstFit);
+        assert_eq!(layout(&worst_fit), "02111....");
+
+        // a disk compacted block by block has no gaps left to fill
+        let fragmented = Day09::parse(&Input::new(EXAMPLE)).unwrap().compacted(&Fragmenting);
+        for strategy in [&FirstFit as &dyn Strategy, &BestFit, &WorstFit] {
+            assert_eq!(fragmented.compacted(strategy), fragmented);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    input::Input,
+    parse::ParseError,
+    search::{self, Paths},
+    solution::{Answer, Solution},
+    Grid, Point,
+};
+
+/// Every position reachable from `trailhead` by climbing exactly one height per step.
+fn trails(grid: &Grid<char>, trailhead: Point) -> Paths<Point, usize> {
+    search::bfs(trailhead, |&pos| {
+        let height = grid[pos] as u8;
+        grid.neighbours(pos)
+            .filter(move |&(_, _, &next)| next as u8 == height + 1)
+            .map(|(_, next, _)| next)
+    })
+}
+
+fn ends<'a>(grid: &'a Grid<char>, trails: &'a Paths<Point, usize>) -> impl Iterator<Item = &'a Point> {
+    trails.iter().map(|(pos, _)| pos).filter(|&&pos| grid[pos] == '9')
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Input = (Grid<char>, Vec<Point>);
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        let grid = input.grid()?;
+        let trailheads = grid.find_all(&'0').collect();
+        Ok((grid, trailheads))
+    }
+
+    fn part1((grid, trailheads): &Self::Input) -> Answer {
+        let mut count = 0;
+        for &trailhead in trailheads {
+            count += ends(grid, &trails(grid, trailhead)).count();
+        }
+
+        count.into()
+    }
+
+    fn part2((grid, trailheads): &Self::Input) -> Answer {
+        let mut distinct_trails = 0;
+        for &trailhead in trailheads {
+            let trails = trails(grid, trailhead);
+            distinct_trails += ends(grid, &trails).map(|end| trails.count_paths(end)).sum::<u64>();
+        }
+
+        distinct_trails.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_10.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day10::part1(&Day10::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(36));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day10::part2(&Day10::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(81));
+    }
+}
@@ -0,0 +1,94 @@
+use std::{collections::HashMap, ops::{AddAssign, SubAssign}};
+
+use crate::{
+    input::Input,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+fn blink(stone_map: &mut HashMap<u64, u64>) {
+    for (&stone, &count) in stone_map.clone().iter() {
+        stone_map.entry(stone).or_insert(count).sub_assign(count);
+        if stone == 0 {
+            stone_map.entry(1).or_insert(0).add_assign(count);
+            trace!("0 -> 1");
+            continue;
+        }
+
+        let stone_str = stone.to_string();
+        let digits_len = stone_str.len();
+        if digits_len % 2 == 0 {
+            let (left, right) = stone_str.split_at(digits_len / 2);
+            let (left, right) = (left.parse::<u64>().unwrap(), right.parse::<u64>().unwrap());
+
+            stone_map.entry(left).or_insert(0).add_assign(count);
+            stone_map.entry(right).or_insert(0).add_assign(count);
+            trace!("{} -> {}, {}", stone, left, right);
+            continue;
+        }
+
+        stone_map.entry(stone * 2024).or_insert(0).add_assign(count);
+        trace!("{} -> {}", stone, stone * 2024);
+    }
+}
+
+fn count_stones(stones: &[u64], blinks: usize) -> u64 {
+    let mut stone_map: HashMap<u64, u64> = HashMap::new();
+
+    for &stone in stones {
+        let stone_count = stone_map.entry(stone).or_insert(0);
+        stone_count.add_assign(1);
+    }
+
+    trace!("{:?}", stone_map);
+
+    for _ in 0..blinks {
+        blink(&mut stone_map);
+    }
+
+    let mut sum = 0;
+    for (_, &count) in stone_map.iter() {
+        sum += count;
+    }
+
+    sum
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        input.text().split_whitespace().map(|x| parse::number(input.text(), x)).collect()
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        count_stones(stones, 25).into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        count_stones(stones, 75).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_11.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day11::part1(&Day11::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(55312));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day11::part2(&Day11::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(65601038650482));
+    }
+}
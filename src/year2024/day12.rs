@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use crate::{
+    input::Input,
+    parse::ParseError,
+    search,
+    solution::{Answer, Solution},
+    trace,
+    verbosity::{self, Verbosity},
+    Direction, Grid, Point,
+};
+
+const CORNER_VECTORS: [Point; 4] = [Point::new(-1, -1), Point::new(-1, 1), Point::new(1, -1), Point::new(1, 1)];
+
+// the debug grid has a border of one tile, so edges outside of the grid can be drawn
+const BORDER: Point = Point::new(1, 1);
+
+fn find_region(grid: &Grid<char>, start: Point) -> Vec<Point> {
+    let plant = grid[start];
+    search::dfs(start, |&pos| {
+        grid.neighbours(pos)
+            .filter(move |&(_, _, &next_plant)| next_plant == plant)
+            .map(|(_, next_pos, _)| next_pos)
+    })
+}
+
+fn calc_prices(grid: &Grid<char>) -> (usize, usize) {
+    let mut regions: Vec<Vec<Point>> = Vec::new();
+    let mut seen = HashSet::new();
+    for pos in grid.positions() {
+        if seen.contains(&pos) {
+            continue;
+        }
+
+        let region = find_region(grid, pos);
+        seen.extend(region.iter().copied());
+        regions.push(region);
+    }
+
+    let mut total_price = 0;
+    let mut total_price_p2 = 0;
+    for region in regions {
+        let area = region.len();
+        let mut perimeter = 0;
+        let start = region[0];
+        let plant = grid[start];
+
+        let mut debug_grid = Grid::new(grid.columns() + 2, grid.rows() + 2, '.');
+
+        let mut edges: Vec<(Point, Point)> = Vec::new();
+        for &pos in &region {
+            debug_grid[pos + BORDER] = plant;
+            for direction in Direction::CARDINAL {
+                let next_pos = pos + direction.get_offset();
+                if grid.get(next_pos) != Some(&plant) {
+                    edges.push((pos, next_pos));
+                    perimeter += 1;
+
+                    let edge_char = match direction {
+                        Direction::North | Direction::South => '-',
+                        _ => '|',
+                    };
+
+                    debug_grid[next_pos + BORDER] = edge_char;
+                }
+            }
+        }
+
+        let mut outer_corners: Vec<Point> = Vec::new();
+        let mut inner_corners: Vec<Point> = Vec::new();
+        for e1 in &edges {
+            for e2 in &edges {
+                if e1 == e2 {
+                    continue;
+                }
+
+                let (pos1, edge1) = e1;
+                let (pos2, edge2) = e2;
+                let v_corner = (*edge1 - *pos1) + (*edge2 - *pos2);
+                let outer_corner = *pos1 + v_corner;
+                let inner_v_corner = -v_corner;
+                let inner_corner = *edge1 + inner_v_corner;
+
+                if edge1 == edge2 && CORNER_VECTORS.contains(&inner_v_corner) {
+                    // inner edge
+                    inner_corners.push(inner_corner);
+
+                    debug_grid[*edge1 + BORDER] = '+';
+                }
+
+                if pos1 == pos2 && CORNER_VECTORS.contains(&v_corner) && !region.contains(&outer_corner) {
+                    // outer edge
+                    outer_corners.push(outer_corner);
+
+                    debug_grid[outer_corner + BORDER] = '+';
+                }
+            }
+        }
+
+        if verbosity::enabled(Verbosity::Trace) {
+            print!("{}", debug_grid);
+        }
+
+        let price = area * perimeter;
+        let sides = inner_corners.len() / 2 + outer_corners.len() / 2;
+        let price_p2 = area * sides;
+        total_price += price;
+        total_price_p2 += price_p2;
+        trace!("{}: {}", plant, sides);
+    }
+
+    if verbosity::enabled(Verbosity::Verbose) {
+        print!("{}", grid);
+    }
+
+    (total_price, total_price_p2)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        input.grid()
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        let (total_price, _) = calc_prices(grid);
+        total_price.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        let (_, total_price_p2) = calc_prices(grid);
+        total_price_p2.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_12.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day12::part1(&Day12::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(1930));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day12::part2(&Day12::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(1206));
+    }
+}
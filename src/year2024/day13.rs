@@ -0,0 +1,246 @@
+use regex::Regex;
+
+use crate::{
+    input::Input,
+    math,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    Point,
+};
+
+const PART2_PRIZE_OFFSET: i64 = 10_000_000_000_000;
+const A_TOKENS: i64 = 3;
+const B_TOKENS: i64 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClawMachine {
+    pub a: Point,
+    pub b: Point,
+    pub prize: Point,
+}
+
+fn cross(p: Point, q: Point) -> i128 {
+    p.x as i128 * q.y as i128 - p.y as i128 * q.x as i128
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    -(-a).div_euclid(b)
+}
+
+impl ClawMachine {
+    /// The cheapest number of presses of A and B reaching the prize, moved by `prize_offset`
+    /// on both axes, if there is any within `press_limit`.
+    pub fn cheapest_presses(&self, prize_offset: i64, press_limit: Option<i64>) -> Option<(i64, i64)> {
+        let prize = self.prize + Point::new(prize_offset, prize_offset);
+        let presses = match cross(self.a, self.b) {
+            0 => self.cheapest_collinear(prize, press_limit.map(i128::from)),
+            det => {
+                // Cramer's rule, the presses have to be whole and non-negative
+                let a = cross(prize, self.b);
+                let b = cross(self.a, prize);
+                (a % det == 0 && b % det == 0)
+                    .then_some((a / det, b / det))
+                    .filter(|&(a, b)| a >= 0 && b >= 0)
+                    .filter(|&(a, b)| press_limit.is_none_or(|limit| a <= limit as i128 && b <= limit as i128))
+            }
+        }?;
+
+        Some((presses.0.try_into().ok()?, presses.1.try_into().ok()?))
+    }
+
+    /// Solves the case of both buttons moving the claw along the same line, where
+    /// there may be many ways to reach the prize, even with buttons moving it in
+    /// opposite directions.
+    fn cheapest_collinear(&self, prize: Point, press_limit: Option<i128>) -> Option<(i128, i128)> {
+        let Some(line) = [self.a, self.b].into_iter().find(|&button| button != Point::ORIGIN) else {
+            return (prize == Point::ORIGIN).then_some((0, 0));
+        };
+        if cross(line, prize) != 0 {
+            return None;
+        }
+
+        // every point on the line is identified by a single coordinate
+        let coordinate = |p: Point| if line.x != 0 { p.x as i128 } else { p.y as i128 };
+        let (step_a, step_b, target) = match (coordinate(self.a), coordinate(self.b), coordinate(prize)) {
+            (a, b, target) if a <= 0 && b <= 0 => (-a, -b, -target),
+            steps => steps,
+        };
+
+        // a button that does not move the claw is never worth pressing
+        if step_a == 0 || step_b == 0 {
+            let step = step_a.max(step_b);
+            let presses = target / step;
+            if target % step != 0 || presses < 0 || press_limit.is_some_and(|limit| presses > limit) {
+                return None;
+            }
+            return Some(if step_a == 0 { (0, presses) } else { (presses, 0) });
+        }
+
+        let (g, s, t) = math::extended_gcd(step_a, step_b);
+        if target % g != 0 {
+            return None;
+        }
+
+        // all solutions are (a0 + k * da, b0 - k * db), find the range of k keeping both within bounds
+        let (a0, b0) = (s * (target / g), t * (target / g));
+        let (da, db) = (step_b / g, step_a / g);
+        let (a_low, a_high) = k_range(a0, da, press_limit);
+        let (b_low, b_high) = k_range(b0, -db, press_limit);
+        let low = a_low.into_iter().chain(b_low).max();
+        let high = a_high.into_iter().chain(b_high).min();
+        if low.zip(high).is_some_and(|(low, high)| low > high) {
+            return None;
+        }
+
+        // the cost is linear in k and never negative, so an unbounded side of
+        // the range only gets more expensive and the cheapest solution is at an end
+        [low, high]
+            .into_iter()
+            .flatten()
+            .map(|k| (a0 + k * da, b0 - k * db))
+            .min_by_key(|&(a, b)| A_TOKENS as i128 * a + B_TOKENS as i128 * b)
+    }
+}
+
+/// The range of `k` keeping `start + k * step` within `0..=limit`, where `None` is unbounded.
+/// `step` must not be zero.
+fn k_range(start: i128, step: i128, limit: Option<i128>) -> (Option<i128>, Option<i128>) {
+    if step > 0 {
+        (Some(div_ceil(-start, step)), limit.map(|limit| (limit - start).div_euclid(step)))
+    } else {
+        (limit.map(|limit| div_ceil(start - limit, -step)), Some(start.div_euclid(-step)))
+    }
+}
+
+fn total_cost(machines: &[ClawMachine], prize_offset: i64, press_limit: Option<i64>) -> i64 {
+    machines
+        .iter()
+        .filter_map(|machine| machine.cheapest_presses(prize_offset, press_limit))
+        .map(|(a, b)| A_TOKENS * a + B_TOKENS * b)
+        .sum()
+}
+
+/// Reads the X and Y of a line matched by `re`, pointing errors at the line or the number that is off.
+fn parse_point(input: &str, line: &str, re: &Regex, expected: &str) -> Result<Point, ParseError> {
+    let Some(c) = re.captures(line) else {
+        return Err(ParseError::at(input, line, format!("expected {}", expected)));
+    };
+    let (_, [x, y]) = c.extract();
+    Ok(Point::new(parse::number(input, x)?, parse::number(input, y)?))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Input = Vec<ClawMachine>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        let lines = [
+            (Regex::new(r"^Button A: X\+([^,]*), Y\+(.*)$").unwrap(), "a button like 'Button A: X+94, Y+34'"),
+            (Regex::new(r"^Button B: X\+([^,]*), Y\+(.*)$").unwrap(), "a button like 'Button B: X+22, Y+67'"),
+            (Regex::new(r"^Prize: X=([^,]*), Y=(.*)$").unwrap(), "a prize like 'Prize: X=8400, Y=5400'"),
+        ];
+
+        input
+            .blocks()
+            .map(|block| {
+                let mut block_lines = block.lines().map(str::trim_end);
+                let mut points = [Point::ORIGIN; 3];
+                for (point, (re, expected)) in points.iter_mut().zip(&lines) {
+                    let Some(line) = block_lines.next() else {
+                        return Err(ParseError::at(input.text(), &block[block.len()..], format!("expected {}", expected)));
+                    };
+                    *point = parse_point(input.text(), line, re, expected)?;
+                }
+                if let Some(extra) = block_lines.next() {
+                    return Err(ParseError::at(input.text(), extra, "expected a blank line after the prize"));
+                }
+
+                let [a, b, prize] = points;
+                Ok(ClawMachine { a, b, prize })
+            })
+            .collect()
+    }
+
+    fn part1(machines: &Self::Input) -> Answer {
+        total_cost(machines, 0, Some(100)).into()
+    }
+
+    fn part2(machines: &Self::Input) -> Answer {
+        total_cost(machines, PART2_PRIZE_OFFSET, None).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_13.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day13::part1(&Day13::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(480));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day13::part2(&Day13::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(875318608908));
+    }
+
+    #[test]
+    fn points_errors_at_the_failing_line() {
+        let err = Day13::parse(&Input::new("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=54x0\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 17, "54x0"));
+
+        let err = Day13::parse(&Input::new("Button A: X+94, Y+34\nButton C: X+22, Y+67\nPrize: X=8400, Y=5400\n")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 0));
+    }
+
+    #[test]
+    fn ignores_spaces_at_line_ends() {
+        let machines = Day13::parse(&Input::new("Button A: X+94, Y+34 \nButton B: X+22, Y+67\t\nPrize: X=8400, Y=5400  \n")).unwrap();
+        assert_eq!(machines, vec![machine((94, 34), (22, 67), (8400, 5400))]);
+    }
+
+    fn machine(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> ClawMachine {
+        ClawMachine { a: a.into(), b: b.into(), prize: prize.into() }
+    }
+
+    #[test]
+    fn respects_the_press_limit() {
+        let machine = machine((94, 34), (22, 67), (8400, 5400));
+        assert_eq!(machine.cheapest_presses(0, Some(100)), Some((80, 40)));
+        assert_eq!(machine.cheapest_presses(0, Some(79)), None);
+    }
+
+    #[test]
+    fn solves_collinear_buttons() {
+        // A moves four times as far for three times the tokens, so it is pressed as often as possible
+        assert_eq!(machine((4, 4), (1, 1), (10, 10)).cheapest_presses(0, None), Some((2, 2)));
+        // here B is cheaper per step
+        assert_eq!(machine((2, 2), (1, 1), (10, 10)).cheapest_presses(0, None), Some((0, 10)));
+        assert_eq!(machine((2, 2), (1, 1), (10, 10)).cheapest_presses(0, Some(9)), Some((1, 8)));
+        assert_eq!(machine((0, 3), (0, 2), (0, 7)).cheapest_presses(0, None), Some((1, 2)));
+    }
+
+    #[test]
+    fn rejects_unreachable_prizes_of_singular_machines() {
+        assert_eq!(machine((1, 1), (2, 2), (3, 4)).cheapest_presses(0, None), None);
+        assert_eq!(machine((2, 2), (4, 4), (3, 3)).cheapest_presses(0, None), None);
+        assert_eq!(machine((0, 0), (0, 0), (1, 1)).cheapest_presses(0, None), None);
+        assert_eq!(machine((0, 0), (0, 0), (0, 0)).cheapest_presses(0, None), Some((0, 0)));
+    }
+
+    #[test]
+    fn solves_collinear_buttons_pulling_in_opposite_directions() {
+        assert_eq!(machine((2, 2), (-1, -1), (3, 3)).cheapest_presses(0, None), Some((2, 1)));
+        assert_eq!(machine((2, 2), (-1, -1), (-3, -3)).cheapest_presses(0, None), Some((0, 3)));
+        assert_eq!(machine((2, 2), (-1, -1), (-3, -3)).cheapest_presses(0, Some(2)), None);
+        assert_eq!(machine((-3, 0), (1, 0), (5, 0)).cheapest_presses(0, None), Some((0, 5)));
+        assert_eq!(machine((-3, 0), (1, 0), (-5, 0)).cheapest_presses(0, None), Some((2, 1)));
+        assert_eq!(machine((4, 4), (-6, -6), (3, 3)).cheapest_presses(0, None), None);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    input::Input,
+    math,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    trace,
+    verbosity::{self, Verbosity},
+    Grid, Point,
+};
+
+const ROWS: usize = 103;
+const COLUMNS: usize = 101;
+
+/// Position and velocity of a robot.
+pub type Robot = (Point, Point);
+
+fn print_grid(grid: &Grid<i32>) {
+    for y in 0..grid.rows() {
+        for &c in grid.row(y) {
+            if c == 0 {
+                print!(".");
+            } else {
+                print!("{}", c);
+            }
+        }
+        println!();
+    }
+}
+
+fn parse_robots(input: &Input) -> Result<Vec<Robot>, ParseError> {
+    let re = regex::Regex::new(r"^p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)$").unwrap();
+
+    let mut robots = Vec::new();
+
+    for line in input.lines() {
+        let Some(c) = re.captures(line) else {
+            return Err(ParseError::at(input.text(), line, "expected a robot like 'p=0,4 v=3,-3'"));
+        };
+
+        let (_, [px, py, vx, vy]) = c.extract();
+        let position = Point::new(parse::number(input.text(), px)?, parse::number(input.text(), py)?);
+        let velocity = Point::new(parse::number(input.text(), vx)?, parse::number(input.text(), vy)?);
+
+        robots.push((position, velocity));
+    }
+
+    Ok(robots)
+}
+
+fn move_robots(robots: &mut [Robot], seconds: i64, columns: usize, rows: usize) {
+    let size = Point::new(columns as i64, rows as i64);
+    for (position, velocity) in robots.iter_mut() {
+        *position = (*position + *velocity * seconds).wrap(size);
+    }
+}
+
+fn count_robots(robots: &[Robot], columns: usize, rows: usize) -> Grid<i32> {
+    let mut grid = Grid::new(columns, rows, 0);
+    for &(pos, _) in robots {
+        grid[pos] += 1;
+    }
+    grid
+}
+
+fn safety_factor(robots: &[Robot], columns: usize, rows: usize) -> i32 {
+    let mut robots = robots.to_vec();
+
+    move_robots(&mut robots, 100, columns, rows);
+
+    let grid = count_robots(&robots, columns, rows);
+    let mut quadrants = vec![0; 4];
+
+    for (position, &robot_count) in grid.iter() {
+        let (x, y) = (position.x as usize, position.y as usize);
+        if x == columns/2 || y == rows/2 {
+            continue;
+        }
+        let quadrant_row = y/((rows/2)+1);
+        let quadrant_col = x/((columns/2)+1);
+        let quadrant_index = 2 * quadrant_row + quadrant_col;
+        quadrants[quadrant_index] += robot_count;
+    }
+
+    let mut safety_factor = 1;
+    for &quadrant in &quadrants {
+        safety_factor *= quadrant;
+    }
+
+    trace!("{:?}", quadrants);
+    safety_factor
+}
+
+/// How spread out the robots are along one axis after `seconds`, as the
+/// variance scaled by the squared number of robots.
+fn spread(robots: &[Robot], seconds: i64, size: i64, axis: fn(Point) -> i64) -> i64 {
+    let n = robots.len() as i64;
+    let (sum, sum_of_squares) = robots
+        .iter()
+        .map(|&(position, velocity)| axis(position + velocity * seconds).rem_euclid(size))
+        .fold((0, 0), |(sum, squares), value| (sum + value, squares + value * value));
+    n * sum_of_squares - sum * sum
+}
+
+/// The robots only form the tree when they bunch up on both axes. Their x positions repeat
+/// every `columns` seconds and their y positions every `rows` seconds, so the least spread
+/// out second of each period is found separately and the two are combined.
+fn find_christmas_tree(robots: &[Robot], columns: usize, rows: usize) -> Option<usize> {
+    let (columns, rows) = (columns as i64, rows as i64);
+    let x_seconds = (0..columns).min_by_key(|&seconds| spread(robots, seconds, columns, |p| p.x))?;
+    let y_seconds = (0..rows).min_by_key(|&seconds| spread(robots, seconds, rows, |p| p.y))?;
+    trace!("x bunches up after {}s, y after {}s", x_seconds, y_seconds);
+
+    let (seconds, _) = math::crt(&[(x_seconds, columns), (y_seconds, rows)])?;
+
+    if verbosity::enabled(Verbosity::Verbose) {
+        let mut robots = robots.to_vec();
+        move_robots(&mut robots, seconds, columns as usize, rows as usize);
+        print_grid(&count_robots(&robots, columns as usize, rows as usize));
+    }
+
+    seconds.try_into().ok()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Input = Vec<Robot>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        parse_robots(input)
+    }
+
+    fn part1(robots: &Self::Input) -> Answer {
+        safety_factor(robots, COLUMNS, ROWS).into()
+    }
+
+    fn part2(robots: &Self::Input) -> Answer {
+        find_christmas_tree(robots, COLUMNS, ROWS).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_14.txt");
+
+    #[test]
+    fn part1_example() {
+        // the example is played on an 11x7 grid
+        assert_eq!(safety_factor(&Day14::parse(&Input::new(EXAMPLE)).unwrap(), 11, 7), 12);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use colored::Colorize;
+
+use crate::{
+    input::Input,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    verbosity::{self, Verbosity},
+    Direction, Grid, Point,
+};
+
+fn print_grid(grid: &Grid<char>) {
+    for y in 0..grid.rows() {
+        for &c in grid.row(y) {
+            if ['<','^','>','v'].contains(&c) {
+                print!("{}", c.to_string().red());
+            } else {
+                print!("{}", c);
+            }
+        }
+        println!();
+    }
+}
+
+fn get_movable_obstacle_block(grid: &Grid<char>, direction: &Direction, pos: Point, visited: &HashMap<Point, char>) -> Option<HashMap<Point, char>> {
+    let mut block: HashMap<Point, char> = HashMap::new();
+    let mut curr = pos;
+    loop {
+        let &obstacle = grid.get(curr)?;
+        if obstacle == '#' {
+            // if we hit a wall, we can't move
+            return None;
+        }
+
+        if obstacle == '.' {
+            // if there is a free space, the block ends
+            break;
+        }
+
+        if obstacle == 'O' {
+            block.insert(curr, obstacle);
+            curr += direction.get_offset();
+        } else if obstacle == '[' || obstacle == ']' {
+            // part 2
+            if direction == &Direction::West || direction == &Direction::East {
+                // when we want to move a box horizontally, we can just proceed as normal
+                block.insert(curr, obstacle);
+                curr += direction.get_offset();
+                continue;
+            }
+
+            block.insert(curr, obstacle);
+
+            // when we want to move a box vertically, we need to check if the other part of the box
+            // is also free to move and if it also pushes other boxes
+            let other_box_part = if obstacle == '[' { curr + Direction::East.get_offset() } else { curr + Direction::West.get_offset() };
+            if visited.contains_key(&other_box_part) {
+                curr += direction.get_offset();
+                continue;
+            }
+
+            let affected_block = get_movable_obstacle_block(grid, direction, other_box_part, &block)?;
+
+            block.extend(affected_block);
+            curr += direction.get_offset();
+        } else {
+            panic!("unknown obstacle {}", obstacle);
+        }
+    }
+
+    Some(block)
+}
+
+fn walk_robot(grid: &mut Grid<char>, instructions: &[Direction], robot: &mut Point) -> usize {
+    for &direction in instructions {
+        let next = *robot + direction.get_offset();
+        let Some(block) = get_movable_obstacle_block(grid, &direction, next, &HashMap::new()) else {
+            continue;
+        };
+
+        for (&obstacle, &c) in block.iter() {
+            let new_obstacle = obstacle + direction.get_offset();
+            if let Some(&other_obstacle) = block.get(&(obstacle + direction.opposite().get_offset())) {
+                grid[obstacle] = other_obstacle;
+            } else {
+                grid[obstacle] = '.';
+            }
+            grid[new_obstacle] = c;
+        }
+
+        // move robot
+        grid[*robot] = '.';
+        grid[next] = '@';
+        *robot = next;
+    }
+
+    let mut sum = 0;
+    for (position, &c) in grid.iter() {
+        if c == 'O' || c == '[' {
+            sum += 100 * position.y as usize + position.x as usize;
+        }
+    }
+
+    sum
+}
+
+fn parse_warehouse(input: &Input) -> Result<(Grid<char>, Vec<Direction>), ParseError> {
+    let mut blocks = input.blocks();
+    let (Some(fields), Some(instructions)) = (blocks.next(), blocks.next()) else {
+        return Err(ParseError::missing(input.text(), "expected the map and the moves separated by a blank line"));
+    };
+
+    let grid = input.grid_with(fields, |c| matches!(c, '#' | '.' | 'O' | '@').then_some(c))?;
+    if grid.find(&'@').is_none() {
+        return Err(ParseError::missing(input.text(), "no robot on the map"));
+    }
+
+    let instructions = instructions
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(i, c)| {
+            Direction::try_from(c)
+                .ok()
+                .filter(Direction::is_cardinal)
+                .ok_or_else(|| ParseError::at(input.text(), &instructions[i..i + c.len_utf8()], "invalid move"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((grid, instructions))
+}
+
+fn resize(grid: &Grid<char>) -> Grid<char> {
+    let mut resized_grid = Grid::new(grid.columns() * 2, grid.rows(), '.');
+
+    for (position, &c) in grid.iter() {
+        let resized = Point::new(position.x * 2, position.y);
+        let right = resized + Direction::East.get_offset();
+        match c {
+            '@' => {
+                resized_grid[resized] = '@';
+                resized_grid[right] = '.';
+            },
+            'O' => {
+                resized_grid[resized] = '[';
+                resized_grid[right] = ']';
+            },
+            '#' | '.' => {
+                resized_grid[resized] = c;
+                resized_grid[right] = c;
+            },
+            _ => panic!("Invalid char {}", c),
+        }
+    }
+
+    resized_grid
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Input = (Grid<char>, Vec<Direction>);
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        parse_warehouse(input)
+    }
+
+    fn part1((grid, instructions): &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+        let mut robot = grid.find(&'@').expect("no robot on the map");
+
+        let sum = walk_robot(&mut grid, instructions, &mut robot);
+        if verbosity::enabled(Verbosity::Verbose) {
+            print_grid(&grid);
+        }
+
+        sum.into()
+    }
+
+    fn part2((grid, instructions): &Self::Input) -> Answer {
+        let mut resized_grid = resize(grid);
+        let mut resized_robot = resized_grid.find(&'@').expect("no robot on the map");
+
+        let sum = walk_robot(&mut resized_grid, instructions, &mut resized_robot);
+        if verbosity::enabled(Verbosity::Verbose) {
+            print_grid(&resized_grid);
+        }
+
+        sum.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/2024_15.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day15::part1(&Day15::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(10092));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day15::part2(&Day15::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(9021));
+    }
+
+    #[test]
+    fn rejects_diagonal_moves() {
+        let err = Day15::parse(&Input::new("#####\n#@.O#\n#####\n\n<>↗v\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 2, "↗"));
+        assert_eq!(err.message, "invalid move");
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use colored::Colorize;
+
+use crate::{
+    input::Input,
+    parse::ParseError,
+    search::{self, Paths},
+    solution::{Answer, Solution},
+    verbosity::{self, Verbosity},
+    Direction, Grid, Point,
+};
+
+fn print_grid(grid: &Grid<char>) {
+    for y in 0..grid.rows() {
+        for &c in grid.row(y) {
+            if ['<','^','>','v','O'].contains(&c) {
+                print!("{}", c.to_string().red());
+            } else {
+                print!("{}", c);
+            }
+        }
+        println!();
+    }
+}
+
+const MOVEMENT_COST: u32 = 1;
+const TURN_COST: u32 = 1000;
+
+/// A reindeer standing on a tile, facing a direction.
+type State = (Point, Direction);
+
+fn successors(grid: &Grid<char>, (pos, direction): State) -> impl Iterator<Item = (State, u32)> + '_ {
+    let forward = pos + direction.get_offset();
+    let turns = [
+        ((pos, direction.rotate_left()), TURN_COST),
+        ((pos, direction.rotate_right()), TURN_COST),
+    ];
+
+    grid.get(forward)
+        .is_some_and(|&c| c != '#')
+        .then_some(((forward, direction), MOVEMENT_COST))
+        .into_iter()
+        .chain(turns)
+}
+
+pub struct Maze {
+    grid: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+/// Searches all cheapest ways from the start to the end, keeping every equal-cost predecessor.
+fn find_best_paths(maze: &Maze) -> Paths<State, u32> {
+    search::dijkstra(
+        (maze.start, Direction::East),
+        |&state| successors(&maze.grid, state),
+        |&(pos, _)| pos == maze.end,
+    )
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Input = Maze;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        let grid = input.grid_with(input.text(), |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c))?;
+
+        let Some(start) = grid.find(&'S') else {
+            return Err(ParseError::missing(input.text(), "no start tile"));
+        };
+        let Some(end) = grid.find(&'E') else {
+            return Err(ParseError::missing(input.text(), "no end tile"));
+        };
+
+        Ok(Maze { grid, start, end })
+    }
+
+    fn part1(maze: &Self::Input) -> Answer {
+        let paths = find_best_paths(maze);
+        let Some(end) = paths.goals().first() else {
+            return Answer::Unsolved;
+        };
+
+        if verbosity::enabled(Verbosity::Verbose) {
+            let mut grid = maze.grid.clone();
+            for &(pos, direction) in paths.path(end).unwrap().iter().skip(1) {
+                if grid[pos] == '.' {
+                    grid[pos] = direction.to_string().chars().next().unwrap();
+                }
+            }
+            print_grid(&grid);
+        }
+
+        paths.cost(end).unwrap().into()
+    }
+
+    fn part2(maze: &Self::Input) -> Answer {
+        let paths = find_best_paths(maze);
+        if paths.goals().is_empty() {
+            return Answer::Unsolved;
+        }
+
+        let tiles: HashSet<Point> = paths.on_shortest_paths(paths.goals()).into_iter().map(|(pos, _)| pos).collect();
+
+        if verbosity::enabled(Verbosity::Verbose) {
+            let mut grid = maze.grid.clone();
+            for &pos in &tiles {
+                grid[pos] = 'O';
+            }
+            print_grid(&grid);
+        }
+
+        tiles.len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = include_str!("../../fixtures/2024_16_1.txt");
+    const EXAMPLE_2: &str = include_str!("../../fixtures/2024_16_2.txt");
+
+    #[test]
+    fn part1_first_example() {
+        assert_eq!(Day16::part1(&Day16::parse(&Input::new(EXAMPLE_1)).unwrap()), Answer::Integer(7036));
+    }
+
+    #[test]
+    fn part2_first_example() {
+        assert_eq!(Day16::part2(&Day16::parse(&Input::new(EXAMPLE_1)).unwrap()), Answer::Integer(45));
+    }
+
+    #[test]
+    fn part1_second_example() {
+        assert_eq!(Day16::part1(&Day16::parse(&Input::new(EXAMPLE_2)).unwrap()), Answer::Integer(11048));
+    }
+
+    #[test]
+    fn part2_second_example() {
+        assert_eq!(Day16::part2(&Day16::parse(&Input::new(EXAMPLE_2)).unwrap()), Answer::Integer(64));
+    }
+
+    #[test]
+    fn stays_within_mazes_without_walls() {
+        let maze = Day16::parse(&Input::new("E.S\n")).unwrap();
+        assert_eq!(Day16::part1(&maze), Answer::Integer(2002));
+        assert_eq!(Day16::part2(&maze), Answer::Integer(3));
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    input::Input,
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub struct Day{{DAY}};
+
+impl Solution for Day{{DAY}} {
+    const YEAR: u16 = {{YEAR}};
+    const DAY: u8 = {{DAY_NUMBER}};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &Input) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/{{YEAR}}_{{DAY}}.txt");
+
+    #[test]
+    #[ignore = "example answer not filled in yet"]
+    fn part1_example() {
+        assert_eq!(Day{{DAY}}::part1(&Day{{DAY}}::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(0));
+    }
+
+    #[test]
+    #[ignore = "example answer not filled in yet"]
+    fn part2_example() {
+        assert_eq!(Day{{DAY}}::part2(&Day{{DAY}}::parse(&Input::new(EXAMPLE)).unwrap()), Answer::Integer(0));
+    }
+}